@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::solution::Part;
+
+const ANSWERS_DIR: &str = "answers";
+
+/// What the puzzle site said about a submitted answer that was not accepted
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hint {
+    High,
+    Low,
+    /// Wrong without a hint, e.g. after too many guesses
+    Wrong
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::High => write!(f, "too high"),
+            Hint::Low => write!(f, "too low"),
+            Hint::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub hint: Hint
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guesses: Vec<Guess>
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<PartAnswers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<PartAnswers>
+}
+
+impl DayAnswers {
+    fn part(&self, part: Part) -> Option<&PartAnswers> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut PartAnswers {
+        match part {
+            Part::One => self.part1.get_or_insert_with(PartAnswers::default),
+            Part::Two => self.part2.get_or_insert_with(PartAnswers::default),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(reason) => write!(f, "FAIL ({})", reason),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Serialize(toml::ser::Error),
+    /// A different answer was already recorded as correct
+    Conflict { day: u8, part: Part, recorded: String }
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => write!(f, "could not access {}: {}", path.display(), err),
+            AnswersError::Parse(path, err) => write!(f, "{} is not a valid answers file: {}", path.display(), err),
+            AnswersError::Serialize(err) => write!(f, "could not write answers: {}", err),
+            AnswersError::Conflict { day, part, recorded } => {
+                write!(f, "day {} part {} already has {} recorded as the correct answer", day, part, recorded)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Known answers and wrong guesses of one year, stored in `answers/<year>.toml`:
+///
+/// ```toml
+/// [day13.part1]
+/// answer = "480"
+/// guesses = [{ answer = "32297", hint = "high" }]
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    year: u16,
+    days: BTreeMap<String, DayAnswers>
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+impl Answers {
+    pub fn empty(year: u16) -> Answers {
+        Answers { year, days: BTreeMap::new() }
+    }
+
+    pub fn path(year: u16) -> PathBuf {
+        Answers::path_in(Path::new(ANSWERS_DIR), year)
+    }
+
+    fn path_in(dir: &Path, year: u16) -> PathBuf {
+        dir.join(format!("{}.toml", year))
+    }
+
+    /// Loads the answers of a year, a year without an answers file has no known answers yet
+    pub fn load(year: u16) -> Result<Answers, AnswersError> {
+        Answers::load_from(Path::new(ANSWERS_DIR), year)
+    }
+
+    /// Like `load`, from another directory than `answers`
+    pub fn load_from(dir: &Path, year: u16) -> Result<Answers, AnswersError> {
+        let path = Answers::path_in(dir, year);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::empty(year)),
+            Err(err) => return Err(AnswersError::Io(path, err)),
+        };
+        let days = toml::from_str(&text).map_err(|err| AnswersError::Parse(path, err))?;
+        Ok(Answers { year, days })
+    }
+
+    pub fn save(&self) -> Result<(), AnswersError> {
+        self.save_to(Path::new(ANSWERS_DIR))
+    }
+
+    pub fn save_to(&self, dir: &Path) -> Result<(), AnswersError> {
+        let path = Answers::path_in(dir, self.year);
+        let text = toml::to_string_pretty(&self.days).map_err(AnswersError::Serialize)?;
+        fs::create_dir_all(dir).map_err(|err| AnswersError::Io(dir.to_path_buf(), err))?;
+        fs::write(&path, text).map_err(|err| AnswersError::Io(path, err))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&PartAnswers> {
+        self.days.get(&day_key(day)).and_then(|day_answers| day_answers.part(part))
+    }
+
+    /// Refuses to replace a different answer that was recorded as correct before
+    pub fn record_answer(&mut self, day: u8, part: Part, answer: &str) -> Result<(), AnswersError> {
+        let part_answers = self.days.entry(day_key(day)).or_default().part_mut(part);
+        match &part_answers.answer {
+            Some(recorded) if recorded != answer => Err(AnswersError::Conflict { day, part, recorded: recorded.clone() }),
+            _ => {
+                part_answers.answer = Some(answer.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    pub fn record_guess(&mut self, day: u8, part: Part, answer: &str, hint: Hint) {
+        let part_answers = self.days.entry(day_key(day)).or_default().part_mut(part);
+        if !part_answers.guesses.iter().any(|guess| guess.answer == answer) {
+            part_answers.guesses.push(Guess { answer: answer.to_string(), hint });
+        }
+    }
+
+    pub fn verify(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        let Some(part_answers) = self.get(day, part) else {
+            return Verdict::Unknown;
+        };
+        if let Some(expected) = &part_answers.answer {
+            if expected == answer {
+                return Verdict::Pass;
+            }
+            return Verdict::Fail(format!("expected {}", expected));
+        }
+        if let Some(guess) = part_answers.guesses.iter().find(|guess| guess.answer == answer) {
+            return Verdict::Fail(format!("already guessed, {}", guess.hint));
+        }
+        match out_of_bounds(&part_answers.guesses, answer) {
+            Some(reason) => Verdict::Fail(reason),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Every "too high" guess is an upper bound and every "too low" guess a lower bound
+fn out_of_bounds(guesses: &[Guess], answer: &str) -> Option<String> {
+    let answer = answer.parse::<i128>().ok()?;
+    let bound = move |hint: Hint| guesses
+        .iter()
+        .filter(move |guess| guess.hint == hint)
+        .filter_map(|guess| guess.answer.parse::<i128>().ok());
+
+    if let Some(upper) = bound(Hint::High).min() {
+        if answer >= upper {
+            return Some(format!("too high, must be below {}", upper));
+        }
+    }
+    if let Some(lower) = bound(Hint::Low).max() {
+        if answer <= lower {
+            return Some(format!("too low, must be above {}", lower));
+        }
+    }
+    None
+}
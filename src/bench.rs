@@ -0,0 +1,260 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::runner;
+use crate::runner::{DaySelection, InputSource, RunError};
+use crate::solution::{DynSolution, InputKind, Part, Registry, MAIN_VARIANT};
+use crate::utils::memo;
+use crate::utils::memo::MemoStats;
+
+#[derive(Copy, Clone, Debug)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    /// The first run always happens, it tells whether the part is solved at all.
+    pub warmup: usize,
+    pub runs: usize
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let median = match sorted.len() % 2 {
+            0 => (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2,
+            _ => sorted[sorted.len() / 2],
+        };
+
+        let nanos: Vec<f64> = samples.iter().map(|sample| sample.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartBench {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub variant: &'static str,
+    /// The panic message instead of timings if the part is not solved yet, or why its input could not be read
+    pub outcome: Result<(Stats, Stats), String>,
+    /// Cache use of the `Memo`s in one run, if there were any
+    pub memo: Option<MemoStats>
+}
+
+pub fn bench_part(solution: &dyn DynSolution,
+                  input: &str,
+                  kind: InputKind,
+                  part: Part,
+                  variant: &'static str,
+                  options: BenchOptions) -> PartBench {
+    let (checked, memo) = memo::recording(|| runner::run_variant(solution, input, kind, part, variant));
+    let outcome = checked.map(|_| {
+        let run = || solution.run_variant(input, kind, part, variant).expect("variant ran before");
+        for _ in 1..options.warmup {
+            run();
+        }
+        let (parse_samples, solve_samples): (Vec<Duration>, Vec<Duration>) = (0..options.runs)
+            .map(|_| run())
+            .map(|result| (result.parse_time, result.solve_time))
+            .unzip();
+        (Stats::from_samples(&parse_samples), Stats::from_samples(&solve_samples))
+    });
+    PartBench { year: solution.year(), day: solution.day(), part, variant, outcome, memo }
+}
+
+/// Benches every selected part and all of its registered variants, like `runner::run_day` a
+/// missing input only fails its own row
+pub fn bench(registry: &Registry,
+             year: u16,
+             selection: DaySelection,
+             parts: &[Part],
+             source: &InputSource,
+             options: BenchOptions) -> Result<Vec<PartBench>, RunError> {
+    let mut benches = Vec::new();
+    for solution in runner::select(registry, year, selection, source)? {
+        for &part in parts {
+            let input = match runner::load_input(solution, part, source) {
+                Ok(input) => input,
+                Err(err) if source.is_explicit() => return Err(err.into()),
+                Err(err) => {
+                    let (year, day) = (solution.year(), solution.day());
+                    benches.push(PartBench { year, day, part, variant: MAIN_VARIANT, outcome: Err(err.to_string()), memo: None });
+                    continue;
+                }
+            };
+            for variant in runner::variant_names(solution, part) {
+                benches.push(bench_part(solution, &input, source.kind(), part, variant, options));
+            }
+        }
+    }
+    Ok(benches)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(default = "main_variant")]
+    pub variant: String,
+    pub parse_median_ns: u64,
+    pub solve_median_ns: u64
+}
+
+fn main_variant() -> String {
+    MAIN_VARIANT.to_string()
+}
+
+impl BaselineEntry {
+    fn total(&self) -> Duration {
+        Duration::from_nanos(self.parse_median_ns + self.solve_median_ns)
+    }
+}
+
+/// Median timings of an earlier bench run, saved as JSON
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<BaselineEntry>
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error)
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::Io(path, err) => write!(f, "could not access baseline {}: {}", path.display(), err),
+            BaselineError::Json(path, err) => write!(f, "{} is not a valid baseline: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    pub fn from_benches(benches: &[PartBench]) -> Baseline {
+        let entries = benches
+            .iter()
+            .filter_map(|bench| {
+                let (parse, solve) = bench.outcome.as_ref().ok()?;
+                Some(BaselineEntry {
+                    year: bench.year,
+                    day: bench.day,
+                    part: bench.part.into(),
+                    variant: bench.variant.to_string(),
+                    parse_median_ns: parse.median.as_nanos() as u64,
+                    solve_median_ns: solve.median.as_nanos() as u64
+                })
+            })
+            .collect();
+        Baseline { entries }
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, BaselineError> {
+        let text = fs::read_to_string(path).map_err(|err| BaselineError::Io(path.to_path_buf(), err))?;
+        serde_json::from_str(&text).map_err(|err| BaselineError::Json(path.to_path_buf(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let text = serde_json::to_string_pretty(self).map_err(|err| BaselineError::Json(path.to_path_buf(), err))?;
+        fs::write(path, text).map_err(|err| BaselineError::Io(path.to_path_buf(), err))
+    }
+
+    fn get(&self, year: u16, day: u8, part: Part, variant: &str) -> Option<&BaselineEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.part == u8::from(part) && entry.variant == variant)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Comparison {
+    /// Not in the baseline
+    New,
+    /// Relative change of the median parse + solve time, in percent
+    Change { percent: f64, regression: bool }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparison::New => write!(f, "new"),
+            Comparison::Change { percent, regression: true } => write!(f, "{:+.1}% REGRESSION", percent),
+            Comparison::Change { percent, regression: false } => write!(f, "{:+.1}%", percent),
+        }
+    }
+}
+
+/// A part regressed when its median got more than `threshold` percent slower than the baseline
+pub fn compare(bench: &PartBench, baseline: &Baseline, threshold: f64) -> Option<Comparison> {
+    let (parse, solve) = bench.outcome.as_ref().ok()?;
+    let Some(entry) = baseline.get(bench.year, bench.day, bench.part, bench.variant) else {
+        return Some(Comparison::New);
+    };
+    let before = entry.total().as_nanos() as f64;
+    let now = (parse.median + solve.median).as_nanos() as f64;
+    let percent = match before > 0.0 {
+        true => (now - before) / before * 100.0,
+        false => 0.0,
+    };
+    Some(Comparison::Change { percent, regression: percent > threshold })
+}
+
+pub fn print_benches(benches: &[PartBench], baseline: Option<(&Baseline, f64)>) {
+    let format = runner::format_duration;
+    let show_memo = benches.iter().any(|bench| bench.memo.is_some());
+    let rows: Vec<Vec<String>> = benches
+        .iter()
+        .map(|bench| {
+            let mut row = vec![bench.year.to_string(), format!("{:02}", bench.day), bench.part.to_string(), bench.variant.to_string()];
+            match &bench.outcome {
+                Ok((parse, solve)) => row.extend([
+                    format(parse.median), format(solve.min), format(solve.median), format(solve.mean), format(solve.stddev)
+                ]),
+                Err(message) => row.extend([format!("<{}>", message), String::new(), String::new(), String::new(), String::new()]),
+            }
+            if show_memo {
+                row.push(bench.memo.map(|memo| memo.to_string()).unwrap_or_default());
+            }
+            if let Some((baseline, threshold)) = baseline {
+                row.push(compare(bench, baseline, threshold).map(|comparison| comparison.to_string()).unwrap_or_default());
+            }
+            row
+        })
+        .collect();
+
+    let mut header = vec![("Year", false), ("Day", true), ("Part", true), ("Variant", false), ("Parse (median)", true),
+                          ("Min", true), ("Median", true), ("Mean", true), ("Stddev", true)];
+    if show_memo {
+        header.push(("Memo hits", true));
+    }
+    if baseline.is_some() {
+        header.push(("vs baseline", true));
+    }
+    runner::print_table(&header, &rows);
+}
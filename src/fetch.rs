@@ -0,0 +1,212 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::utils::input;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The session cookie of a logged in browser, read from this variable first
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Holds the session token when `AOC_SESSION` is not set, ignored by git
+pub const SESSION_FILE: &str = ".aoc-session";
+/// Contact info (an email or repository url) appended to the User-Agent, as the puzzle site asks for
+pub const CONTACT_VAR: &str = "AOC_CONTACT";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"), " input fetcher");
+/// Puzzles unlock at midnight EST
+const UNLOCK_HOUR_UTC: i64 = 5;
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    /// The puzzle is not unlocked yet, asking for its input would only bother the server
+    Locked(u16, u8),
+    Status { url: String, status: u16, message: String },
+    EmptyBody(String),
+    Http(String),
+    Io(PathBuf, io::Error)
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "no session token, set {} or write it to {}", SESSION_VAR, SESSION_FILE),
+            FetchError::Locked(year, day) => write!(f, "{} day {} is not unlocked yet", year, day),
+            FetchError::Status { url, status, message } => write!(f, "{} returned {}: {}", url, status, message),
+            FetchError::EmptyBody(url) => write!(f, "{} returned an empty input", url),
+            FetchError::Http(err) => write!(f, "request failed: {}", err),
+            FetchError::Io(path, err) => write!(f, "could not access {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String
+}
+
+/// The only request the fetcher makes, so tests can swap in their own client or server
+pub trait HttpClient {
+    /// Error statuses are responses too, only failing to get any response is an error
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, FetchError>;
+}
+
+pub struct UreqClient {
+    agent: ureq::Agent
+}
+
+impl UreqClient {
+    pub fn new() -> UreqClient {
+        UreqClient { agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build() }
+    }
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        UreqClient::new()
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, FetchError> {
+        let request = headers
+            .iter()
+            .fold(self.agent.get(url), |request, (name, value)| request.set(name, value));
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(FetchError::Http(err.to_string())),
+        };
+        let status = response.status();
+        let body = response.into_string().map_err(|err| FetchError::Http(err.to_string()))?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// `None` if no session is configured, it is only needed once an input has to be downloaded
+pub fn load_session() -> Result<Option<String>, FetchError> {
+    if let Ok(session) = std::env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(Some(session.trim().to_string()));
+        }
+    }
+    match fs::read_to_string(SESSION_FILE) {
+        Ok(session) if !session.trim().is_empty() => Ok(Some(session.trim().to_string())),
+        Ok(_) => Ok(None),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(FetchError::Io(PathBuf::from(SESSION_FILE), err)),
+    }
+}
+
+pub fn user_agent() -> String {
+    match std::env::var(CONTACT_VAR) {
+        Ok(contact) if !contact.trim().is_empty() => format!("{} ({})", USER_AGENT, contact.trim()),
+        _ => USER_AGENT.to_string(),
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+pub fn is_unlocked(year: u16, day: u8, now: SystemTime) -> bool {
+    let unlock = days_from_civil(year as i64, 12, day as i64) * 86400 + UNLOCK_HOUR_UTC * 3600;
+    let now = now.duration_since(UNIX_EPOCH).map(|since| since.as_secs() as i64).unwrap_or(0);
+    now >= unlock
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchOptions {
+    pub base_url: String,
+    pub input_dir: PathBuf,
+    pub user_agent: String,
+    /// Least time between two requests
+    pub min_interval: Duration
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        FetchOptions {
+            base_url: BASE_URL.to_string(),
+            input_dir: input::input_dir(),
+            user_agent: user_agent(),
+            min_interval: Duration::from_secs(3)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf)
+}
+
+pub struct Fetcher<C: HttpClient> {
+    client: C,
+    session: Option<String>,
+    options: FetchOptions,
+    last_request: Option<Instant>
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, session: Option<String>, options: FetchOptions) -> Fetcher<C> {
+        Fetcher { client, session, options, last_request: None }
+    }
+
+    /// Downloads the input into the input directory, unless it is already there
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<Fetched, FetchError> {
+        // an empty file counts as missing and is filled in, anything else is never downloaded again
+        let path = match input::input_path_in(&self.options.input_dir, year, day) {
+            Ok(path) if fs::metadata(&path).map(|metadata| metadata.len() > 0).unwrap_or(false) => {
+                return Ok(Fetched::Cached(path));
+            }
+            Ok(placeholder) => placeholder,
+            Err(_) => self.options.input_dir.join(format!("{}.txt", input::file_stem(year, day))),
+        };
+        if !is_unlocked(year, day, SystemTime::now()) {
+            return Err(FetchError::Locked(year, day));
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.options.base_url.trim_end_matches('/'), year, day);
+        let body = self.request(&url)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| FetchError::Io(dir.to_path_buf(), err))?;
+        }
+        fs::write(&path, body).map_err(|err| FetchError::Io(path.clone(), err))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn request(&mut self, url: &str) -> Result<String, FetchError> {
+        let cookie = match &self.session {
+            Some(session) => format!("session={}", session),
+            None => return Err(FetchError::NoSession),
+        };
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.options.min_interval.saturating_sub(last_request.elapsed()));
+        }
+        let response = self.client.get(url, &[("Cookie", &cookie), ("User-Agent", &self.options.user_agent)]);
+        self.last_request = Some(Instant::now());
+
+        let response = response?;
+        if response.status != 200 {
+            let message = response.body.lines().next().unwrap_or_default().trim().to_string();
+            return Err(FetchError::Status { url: url.to_string(), status: response.status, message });
+        }
+        if response.body.trim().is_empty() {
+            return Err(FetchError::EmptyBody(url.to_string()));
+        }
+        Ok(response.body)
+    }
+}
@@ -1,26 +1,320 @@
-use std::time::{Instant, SystemTime};
-
-macro_rules! run_day {
-    ($day:expr) => {{
-        print!("Day {:02}: \n", $day);
-        let exe_name = format!("day{:02}.exe", $day);
-        let exe_path = std::env::current_exe()
-            .unwrap()
-            .parent()
-            .unwrap()
-            .join(&exe_name);
-        let start = Instant::now();
-        let result = std::process::Command::new(exe_path)
-            .output()
-            .expect(&format!("Failed to execute {}", exe_name));
-        let duration = start.elapsed();
-        println!("{}", String::from_utf8_lossy(&result.stdout));
-        println!("Time: {:?}", duration);
-    }};
-}
-
-fn main() {
-    run_day!(01);
-    // run_day!(02);
-    // Add more days as you progress
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use advent_of_code_2024::answers::{Answers, Hint};
+use advent_of_code_2024::bench;
+use advent_of_code_2024::bench::{Baseline, BenchOptions, Comparison};
+use advent_of_code_2024::fetch;
+use advent_of_code_2024::fetch::{FetchOptions, Fetched, Fetcher, UreqClient};
+use advent_of_code_2024::runner;
+use advent_of_code_2024::runner::{DaySelection, InputSource};
+use advent_of_code_2024::scaffold;
+use advent_of_code_2024::solution::Part;
+use advent_of_code_2024::solutions::REGISTRY;
+use advent_of_code_2024::solutions::day06::{path_color, Lab};
+use advent_of_code_2024::utils::image::Image;
+
+#[derive(Parser, Debug)]
+#[command(about = "Runs the Advent of Code solutions", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Records the correct answer, or a rejected guess, in answers/<year>.toml
+    Record(RecordArgs),
+    /// Times every selected part over several runs
+    Bench(BenchArgs),
+    /// Generates a new day from src/solutions/template.rs and registers it
+    New(NewArgs),
+    /// Downloads puzzle inputs into input/, days that already have one are skipped
+    Fetch(FetchArgs),
+    /// Draws the route of the guard of 2024 day 6, in the terminal and as PPM images if asked
+    Visualize(VisualizeArgs)
+}
+
+#[derive(Args, Debug)]
+struct VisualizeArgs {
+    #[command(flatten)]
+    source: SourceArgs,
+
+    /// Also save the drawing as a PPM image
+    #[arg(long)]
+    image: Option<PathBuf>,
+
+    /// Save a PPM image into this directory after every straight stretch of the route, to animate it.
+    /// Every frame is a full-size image: a puzzle input gives well over a hundred, about 0.8 MB each at --scale 4
+    #[arg(long)]
+    frames: Option<PathBuf>,
+
+    /// Pixels per cell in the images
+    #[arg(long, default_value_t = 4)]
+    scale: usize
+}
+
+#[derive(Args, Debug)]
+struct FetchArgs {
+    #[arg(long, default_value_t = 2024)]
+    year: u16,
+
+    /// A single day, an inclusive range like 1-5, or all
+    #[arg(long, default_value = "all")]
+    day: DaySelection
+}
+
+#[derive(Args, Debug)]
+struct NewArgs {
+    #[arg(long, default_value_t = 2024)]
+    year: u16,
+
+    #[arg(long)]
+    day: u8
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Timed runs per part, at least one
+    #[arg(long, default_value_t = 10, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    runs: usize,
+
+    /// Untimed runs per part before timing
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Save the median timings as a JSON baseline
+    #[arg(long)]
+    save_baseline: Option<PathBuf>,
+
+    /// Compare against a baseline saved earlier
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Percentage a part may get slower than the baseline before it counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64
+}
+
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("verdict").required(true).multiple(false)))]
+struct RecordArgs {
+    #[arg(long, default_value_t = 2024)]
+    year: u16,
+
+    #[arg(long)]
+    day: u8,
+
+    #[arg(long)]
+    part: Part,
+
+    /// The answer was accepted
+    #[arg(long, group = "verdict")]
+    correct: Option<String>,
+
+    /// The answer was rejected as too high
+    #[arg(long, group = "verdict")]
+    too_high: Option<String>,
+
+    /// The answer was rejected as too low
+    #[arg(long, group = "verdict")]
+    too_low: Option<String>,
+
+    /// The answer was rejected without a hint
+    #[arg(long, group = "verdict")]
+    wrong: Option<String>
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Puzzle year
+    #[arg(long, default_value_t = 2024)]
+    year: u16,
+
+    /// A single day, an inclusive range like 1-5, or all
+    #[arg(long, default_value = "all")]
+    day: DaySelection,
+
+    /// Only run part 1 or 2
+    #[arg(long)]
+    part: Option<Part>,
+
+    #[command(flatten)]
+    source: SourceArgs
+}
+
+#[derive(Args, Debug)]
+struct SourceArgs {
+    /// Read the puzzle input from this file instead of input/
+    #[arg(long, conflicts_with_all = ["example", "stdin"])]
+    input: Option<PathBuf>,
+
+    /// Use the example input from example_input/
+    #[arg(long, conflicts_with = "stdin")]
+    example: bool,
+
+    /// Read the puzzle input from stdin
+    #[arg(long)]
+    stdin: bool
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+impl SourceArgs {
+    fn input_source(&self) -> std::io::Result<InputSource> {
+        if let Some(path) = &self.input {
+            return Ok(InputSource::File(path.clone()));
+        }
+        if self.example {
+            return Ok(InputSource::Example);
+        }
+        if self.stdin {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            return Ok(InputSource::Text(input));
+        }
+        Ok(InputSource::Puzzle)
+    }
+}
+
+fn record(args: &RecordArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut answers = Answers::load(args.year)?;
+    if let Some(answer) = &args.correct {
+        answers.record_answer(args.day, args.part, answer)?;
+    }
+    for (guess, hint) in [(&args.too_high, Hint::High), (&args.too_low, Hint::Low), (&args.wrong, Hint::Wrong)] {
+        if let Some(answer) = guess {
+            answers.record_guess(args.day, args.part, answer, hint);
+        }
+    }
+    answers.save()?;
+    Ok(())
+}
+
+fn run(args: &RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let source = args.source.input_source()?;
+    let reports = runner::run(&REGISTRY, args.year, args.day, &args.parts(), &source)?;
+    // the recorded answers only apply to the real puzzle input
+    let answers = match source {
+        InputSource::Puzzle => Some(Answers::load(args.year)?),
+        _ => None,
+    };
+    runner::print_summary(&reports, answers.as_ref());
+
+    let disagreements: usize = reports.iter().map(|report| report.disagreements().len()).sum();
+    if disagreements > 0 {
+        return Err(format!("{} variant(s) disagree with the main implementation", disagreements).into());
+    }
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let source = args.run.source.input_source()?;
+    let options = BenchOptions { warmup: args.warmup, runs: args.runs };
+    let benches = bench::bench(&REGISTRY, args.run.year, args.run.day, &args.run.parts(), &source, options)?;
+
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    bench::print_benches(&benches, baseline.as_ref().map(|baseline| (baseline, args.threshold)));
+
+    if let Some(path) = &args.save_baseline {
+        Baseline::from_benches(&benches).save(path)?;
+    }
+    if let Some(baseline) = &baseline {
+        let regressions = benches
+            .iter()
+            .filter(|part_bench| matches!(bench::compare(part_bench, baseline, args.threshold),
+                                          Some(Comparison::Change { regression: true, .. })))
+            .count();
+        if regressions > 0 {
+            return Err(format!("{} part(s) regressed more than {}%", regressions, args.threshold).into());
+        }
+    }
+    Ok(())
+}
+
+fn new(args: &NewArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let scaffolded = scaffold::new_day(Path::new("."), &REGISTRY, args.year, args.day)?;
+    for file in &scaffolded.files {
+        println!("wrote {}", file.display());
+    }
+    println!("next: fetch the input, paste the example, declare the example answers of {}::{} and list them in tests/examples.rs",
+             scaffolded.module, scaffolded.struct_name);
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // only registered days, like run and bench
+    let solutions = runner::select(&REGISTRY, args.year, args.day, &InputSource::Puzzle)?;
+    let mut fetcher = Fetcher::new(UreqClient::new(), fetch::load_session()?, FetchOptions::default());
+    for solution in solutions {
+        match fetcher.fetch(solution.year(), solution.day())? {
+            Fetched::Cached(path) => println!("cached {}", path.display()),
+            Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn visualize(args: &VisualizeArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let day06 = REGISTRY.get(2024, 6).expect("day 6 is registered");
+    let lab = Lab::parse(&runner::load_input(day06, Part::One, &args.source.input_source()?)?)?;
+
+    if let Some(dir) = &args.frames {
+        fs::create_dir_all(dir)?;
+    }
+    let mut frames = 0;
+    let mut saved = Ok(());
+    let drawing = lab.draw_path(|drawing| {
+        if let (Some(dir), Ok(())) = (&args.frames, &saved) {
+            let path = dir.join(format!("frame_{:04}.ppm", frames));
+            saved = Image::from_grid(drawing, args.scale, path_color).save_ppm(&path);
+            frames += 1;
+        }
+    });
+    saved?;
+
+    print!("{}", drawing);
+    if let Some(path) = &args.image {
+        Image::from_grid(&drawing, args.scale, path_color).save_ppm(path)?;
+        println!("wrote {}", path.display());
+    }
+    if let Some(dir) = &args.frames {
+        println!("wrote {} frames to {}", frames, dir.display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match &cli.command {
+        Some(Command::Record(args)) => record(args),
+        Some(Command::Bench(args)) => bench(args),
+        Some(Command::New(args)) => new(args),
+        Some(Command::Fetch(args)) => fetch(args),
+        Some(Command::Visualize(args)) => visualize(args),
+        None => run(&cli.run),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
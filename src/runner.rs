@@ -0,0 +1,342 @@
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Once;
+use std::time::Duration;
+use crate::answers::Answers;
+use crate::solution::{DynSolution, InputKind, Part, PartResult, Registry, MAIN_VARIANT};
+use crate::utils;
+use crate::utils::InputError;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DaySelection {
+    All,
+    Day(u8),
+    Range(u8, u8)
+}
+
+impl DaySelection {
+    fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Day(selected) => *selected == day,
+            DaySelection::Range(first, last) => (*first..=*last).contains(&day),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    /// Accepts a single day (`6`), an inclusive range (`1-5`) or `all`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| day.trim().parse::<u8>().map_err(|_| format!("invalid day '{}'", day));
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        if let Some((first, last)) = s.split_once('-') {
+            return Ok(DaySelection::Range(parse_day(first)?, parse_day(last)?));
+        }
+        Ok(DaySelection::Day(parse_day(s)?))
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySelection::All => write!(f, "all days"),
+            DaySelection::Day(day) => write!(f, "day {}", day),
+            DaySelection::Range(first, last) => write!(f, "days {}-{}", first, last),
+        }
+    }
+}
+
+/// Where the puzzle input of a run comes from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    /// Resolved by `utils::input_path`
+    Puzzle,
+    /// Resolved by `utils::example_path`
+    Example,
+    File(PathBuf),
+    /// Text that was already read, e.g. from stdin
+    Text(String)
+}
+
+impl InputSource {
+    /// A file or stdin given for a single day. A problem with it fails the whole run,
+    /// a missing puzzle or example input only fails the rows of its day.
+    pub fn is_explicit(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Text(_))
+    }
+
+    /// Files and stdin count as puzzle inputs, only `Example` gets the example settings of a day
+    pub fn kind(&self) -> InputKind {
+        match self {
+            InputSource::Example => InputKind::Example,
+            _ => InputKind::Puzzle,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    UnknownYear(u16),
+    UnknownDay { year: u16, selection: DaySelection },
+    /// A single input (file or stdin) was given for more than one day
+    AmbiguousInput,
+    Input(InputError)
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::UnknownYear(year) => write!(f, "no solutions for year {}", year),
+            RunError::UnknownDay { year, selection } => write!(f, "no solution for {} of {}", selection, year),
+            RunError::AmbiguousInput => write!(f, "an input file or stdin can only be used for a single day"),
+            RunError::Input(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<InputError> for RunError {
+    fn from(err: InputError) -> Self {
+        RunError::Input(err)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub part: Part,
+    /// `MAIN_VARIANT` or the name of a registered variant of the part
+    pub variant: &'static str,
+    /// The answer and timings, or the panic message if the part blew up (`todo!()` etc.)
+    pub outcome: Result<PartResult, String>
+}
+
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartReport>
+}
+
+impl DayReport {
+    /// Variants whose answer differs from the main implementation of their part.
+    /// Variants that panicked, or of a part that panicked, are not compared.
+    pub fn disagreements(&self) -> Vec<(&PartReport, &str)> {
+        self.parts
+            .iter()
+            .filter(|report| report.variant != MAIN_VARIANT)
+            .filter_map(|report| {
+                let main = self.parts.iter().find(|main| main.part == report.part && main.variant == MAIN_VARIANT)?;
+                let main_answer = &main.outcome.as_ref().ok()?.answer;
+                let answer = &report.outcome.as_ref().ok()?.answer;
+                (answer != main_answer).then_some((report, main_answer.as_str()))
+            })
+            .collect()
+    }
+}
+
+pub fn load_input(solution: &dyn DynSolution, part: Part, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Puzzle => utils::read_input(solution.year(), solution.day()),
+        InputSource::Example => utils::read_example(solution.year(), solution.day(), part),
+        InputSource::File(path) => utils::read_input_from_path(path),
+        InputSource::Text(text) => Ok(text.clone()),
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    String::from("panicked")
+}
+
+thread_local! {
+    static RUNNING_PART: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs, once per process, a panic hook that stays quiet while a part runs on the current thread,
+/// the message ends up in the report instead. Panics anywhere else still go to the previous hook.
+fn quiet_part_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !RUNNING_PART.with(Cell::get) {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs a single part, unfinished parts panic so those are caught and reported instead
+pub fn run_part(solution: &dyn DynSolution, input: &str, kind: InputKind, part: Part) -> Result<PartResult, String> {
+    run_variant(solution, input, kind, part, MAIN_VARIANT)
+}
+
+/// Like `run_part`, for one of the registered variants of the part
+pub fn run_variant(solution: &dyn DynSolution,
+                   input: &str,
+                   kind: InputKind,
+                   part: Part,
+                   variant: &str) -> Result<PartResult, String> {
+    quiet_part_panics();
+    let running = RUNNING_PART.replace(true);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| solution.run_variant(input, kind, part, variant)));
+    RUNNING_PART.set(running);
+    outcome
+        .map_err(panic_message)?
+        .ok_or_else(|| format!("no variant '{}' of part {}", variant, part))
+}
+
+/// The main implementation of a part first, then its registered variants
+pub fn variant_names(solution: &dyn DynSolution, part: Part) -> Vec<&'static str> {
+    let mut names = vec![MAIN_VARIANT];
+    names.extend(solution.variants(part));
+    names
+}
+
+/// Every part and its variants. A part whose puzzle or example input cannot be read gets a single
+/// failed row, so the other days of a run still get their answers.
+pub fn run_day(solution: &dyn DynSolution, parts: &[Part], source: &InputSource) -> Result<DayReport, RunError> {
+    let mut part_reports = Vec::new();
+    for &part in parts {
+        let input = match load_input(solution, part, source) {
+            Ok(input) => input,
+            Err(err) if source.is_explicit() => return Err(err.into()),
+            Err(err) => {
+                part_reports.push(PartReport { part, variant: MAIN_VARIANT, outcome: Err(err.to_string()) });
+                continue;
+            }
+        };
+        for variant in variant_names(solution, part) {
+            part_reports.push(PartReport { part, variant, outcome: run_variant(solution, &input, source.kind(), part, variant) });
+        }
+    }
+    Ok(DayReport { year: solution.year(), day: solution.day(), parts: part_reports })
+}
+
+/// Every registered solution of `year` that matches the selection, sorted by day.
+/// A single input file or stdin can only be used when that selects one day.
+pub fn select(registry: &Registry,
+              year: u16,
+              selection: DaySelection,
+              source: &InputSource) -> Result<Vec<&'static dyn DynSolution>, RunError> {
+    let solutions = registry.year(year);
+    if solutions.is_empty() {
+        return Err(RunError::UnknownYear(year));
+    }
+    let selected: Vec<&'static dyn DynSolution> = solutions
+        .into_iter()
+        .filter(|solution| selection.contains(solution.day()))
+        .collect();
+    if selected.is_empty() {
+        return Err(RunError::UnknownDay { year, selection });
+    }
+    if selected.len() > 1 && source.is_explicit() {
+        return Err(RunError::AmbiguousInput);
+    }
+    Ok(selected)
+}
+
+pub fn run(registry: &Registry,
+           year: u16,
+           selection: DaySelection,
+           parts: &[Part],
+           source: &InputSource) -> Result<Vec<DayReport>, RunError> {
+    select(registry, year, selection, source)?
+        .into_iter()
+        .map(|solution| run_day(solution, parts, source))
+        .collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// Prints rows under a header, every column as wide as its widest cell.
+/// Columns flagged `true` in the header are right aligned.
+pub fn print_table(header: &[(&str, bool)], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|(title, _)| title.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(header.iter().zip(&widths))
+            .map(|(cell, ((_, right_aligned), &width))| match right_aligned {
+                true => format!("{:>width$}", cell),
+                false => format!("{:<width$}", cell),
+            })
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    print_row(&header.iter().map(|(title, _)| title.to_string()).collect::<Vec<String>>());
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)));
+    rows.iter().for_each(|row| print_row(row));
+}
+
+/// Prints answers and timings of every part, checked against the known answers when given.
+/// Variants are checked against the main implementation of their part instead.
+pub fn print_summary(reports: &[DayReport], answers: Option<&Answers>) {
+    let show_variants = reports.iter().flat_map(|report| &report.parts).any(|report| report.variant != MAIN_VARIANT);
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .flat_map(|report| {
+            let disagreements = report.disagreements();
+            report.parts.iter().map(move |part_report| {
+                let check = match part_report.variant {
+                    MAIN_VARIANT => answers.zip(part_report.outcome.as_ref().ok())
+                        .map(|(answers, result)| answers.verify(report.day, part_report.part, &result.answer).to_string()),
+                    _ => part_report.outcome.as_ref().ok().map(|_| {
+                        match disagreements.iter().find(|(disagreeing, _)| std::ptr::eq(*disagreeing, part_report)) {
+                            Some((_, main_answer)) => format!("DISAGREES (main: {})", main_answer),
+                            None => String::from("AGREES"),
+                        }
+                    }),
+                };
+                let (answer, parse_time, solve_time) = match &part_report.outcome {
+                    Ok(result) => (result.answer.clone(), format_duration(result.parse_time), format_duration(result.solve_time)),
+                    Err(message) => (format!("<{}>", message), String::from("-"), String::from("-")),
+                };
+                let mut row = vec![report.year.to_string(), format!("{:02}", report.day), part_report.part.to_string()];
+                if show_variants {
+                    row.push(part_report.variant.to_string());
+                }
+                row.extend([answer, check.unwrap_or_else(|| String::from("-")), parse_time, solve_time]);
+                row
+            })
+        })
+        .collect();
+
+    let mut header = vec![("Year", false), ("Day", true), ("Part", true)];
+    if show_variants {
+        header.push(("Variant", false));
+    }
+    header.extend([("Answer", false), ("Check", false), ("Parse", true), ("Solve", true)]);
+    print_table(&header, &rows);
+
+    let total: Duration = reports
+        .iter()
+        .flat_map(|report| report.parts.iter())
+        .filter(|part_report| part_report.variant == MAIN_VARIANT)
+        .filter_map(|part_report| part_report.outcome.as_ref().ok())
+        .map(|result| result.parse_time + result.solve_time)
+        .sum();
+    println!("\nTotal time: {}", format_duration(total));
+}
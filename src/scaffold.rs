@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::solution::Registry;
+use crate::utils::input;
+
+const TEMPLATE: &str = include_str!("solutions/template.rs");
+
+const LIB_FILE: &str = "src/lib.rs";
+const SOLUTIONS_DIR: &str = "src/solutions";
+const INPUT_DIR: &str = "input";
+const EXAMPLE_INPUT_DIR: &str = "example_input";
+
+/// Where the module declarations and registry entries of new days are added in `src/lib.rs`
+const REGISTRY_START: &str = "    pub static REGISTRY: Registry = Registry::new(&[\n";
+const REGISTRY_END: &str = "    ]);\n";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    AlreadyExists(u16, u8),
+    /// `template.rs` or `lib.rs` no longer look like what the scaffolding expects
+    UnexpectedLayout(String),
+    Io(PathBuf, io::Error)
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "day {} is not a puzzle day, expected 1-25", day),
+            ScaffoldError::AlreadyExists(year, day) => write!(f, "{} day {} already exists", year, day),
+            ScaffoldError::UnexpectedLayout(reason) => write!(f, "cannot scaffold: {}", reason),
+            ScaffoldError::Io(path, err) => write!(f, "could not access {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Everything `new_day` created, relative to the repository root
+#[derive(Clone, Debug)]
+pub struct Scaffolded {
+    pub module: String,
+    pub struct_name: String,
+    pub files: Vec<PathBuf>
+}
+
+fn replace_once(text: &str, from: &str, to: &str, file: &str) -> Result<String, ScaffoldError> {
+    if !text.contains(from) {
+        return Err(ScaffoldError::UnexpectedLayout(format!("'{}' not found in {}", from.trim(), file)));
+    }
+    Ok(text.replacen(from, to, 1))
+}
+
+/// The template with its struct, year and day filled in
+fn generate_solution(year: u16, day: u8, struct_name: &str) -> Result<String, ScaffoldError> {
+    let source = replace_once(TEMPLATE, "pub struct Template;", &format!("pub struct {};", struct_name), "template.rs")?;
+    let source = replace_once(&source, "impl Solution for Template {", &format!("impl Solution for {} {{", struct_name), "template.rs")?;
+    let source = replace_once(&source, "const YEAR: u16 = 2024;", &format!("const YEAR: u16 = {};", year), "template.rs")?;
+    let source = replace_once(&source, "const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day), "template.rs")?;
+    replace_once(&source, "// `new` generates every day from this file, see scaffold.rs\n\n", "", "template.rs")
+}
+
+/// Declares the module right above the registry and appends it to the registry
+fn register(lib: &str, module: &str, struct_name: &str) -> Result<String, ScaffoldError> {
+    let start = lib
+        .find(REGISTRY_START)
+        .ok_or_else(|| ScaffoldError::UnexpectedLayout(String::from("registry not found in lib.rs")))?;
+    let end = start + lib[start..]
+        .find(REGISTRY_END)
+        .ok_or_else(|| ScaffoldError::UnexpectedLayout(String::from("end of the registry not found in lib.rs")))?;
+
+    let mut registered = String::new();
+    registered.push_str(lib[..start].trim_end());
+    registered.push_str(&format!("\n    pub mod {};\n\n", module));
+    registered.push_str(&lib[start..end]);
+    registered.push_str(&format!("        &{}::{},\n", module, struct_name));
+    registered.push_str(&lib[end..]);
+    Ok(registered)
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| ScaffoldError::Io(dir.to_path_buf(), err))?;
+    }
+    fs::write(path, contents).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+/// Generates `src/solutions/<module>.rs` from the template, registers it in `src/lib.rs` and
+/// creates empty input and example files. Until they are filled in, only the rows of the day
+/// fail, and `fetch` downloads into the empty input. Refuses to touch a day that already exists.
+pub fn new_day(root: &Path, registry: &Registry, year: u16, day: u8) -> Result<Scaffolded, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    let module = input::file_stem(year, day);
+    let struct_name = format!("Day{:02}", day);
+    let solution_path = root.join(SOLUTIONS_DIR).join(format!("{}.rs", module));
+    if registry.get(year, day).is_some() || solution_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(year, day));
+    }
+
+    let lib_path = root.join(LIB_FILE);
+    let lib = fs::read_to_string(&lib_path).map_err(|err| ScaffoldError::Io(lib_path.clone(), err))?;
+    let registered_lib = register(&lib, &module, &struct_name)?;
+    let solution = generate_solution(year, day, &struct_name)?;
+
+    write_new(&solution_path, &solution)?;
+    fs::write(&lib_path, registered_lib).map_err(|err| ScaffoldError::Io(lib_path.clone(), err))?;
+    let mut files = vec![solution_path, lib_path];
+
+    // an existing input or example is never overwritten
+    for dir in [INPUT_DIR, EXAMPLE_INPUT_DIR] {
+        let placeholder = root.join(dir).join(format!("{}.txt", module));
+        if !placeholder.exists() {
+            write_new(&placeholder, "")?;
+            files.push(placeholder);
+        }
+    }
+
+    Ok(Scaffolded { module, struct_name, files })
+}
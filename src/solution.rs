@@ -0,0 +1,219 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// Whether a part runs on the puzzle input or on the example of the puzzle text
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InputKind {
+    Puzzle,
+    Example
+}
+
+/// Name of the `part_one`/`part_two` implementation next to its registered variants
+pub const MAIN_VARIANT: &str = "main";
+
+pub type SolveFn<S, A> = for<'a, 'b> fn(&'b <S as Solution>::Input<'a>) -> A;
+
+/// An alternative implementation of a part, e.g. a parallel or a more idiomatic version
+pub struct Variant<S: Solution + ?Sized, A> {
+    pub name: &'static str,
+    pub solve: SolveFn<S, A>
+}
+
+/// A single puzzle, identified by year and day.
+///
+/// `parse` turns the raw input into whatever both parts work on, the parts
+/// then return anything that can be printed as an answer.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    type AnswerOne: Display;
+    type AnswerTwo: Display;
+
+    /// Expected answers on the example input, checked by the example regression tests
+    const EXAMPLE_ONE: Option<&'static str> = None;
+    const EXAMPLE_TWO: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    /// Parses the example input, for days whose example uses other settings than the puzzle,
+    /// like a smaller grid. The same as `parse` by default.
+    fn parse_example(input: &str) -> Self::Input<'_> {
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::AnswerOne;
+
+    fn part_two(input: &Self::Input<'_>) -> Self::AnswerTwo;
+
+    /// Other implementations of part one, the runner checks they agree with `part_one`
+    fn part_one_variants() -> Vec<Variant<Self, Self::AnswerOne>> {
+        Vec::new()
+    }
+
+    fn part_two_variants() -> Vec<Variant<Self, Self::AnswerTwo>> {
+        Vec::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration
+}
+
+/// Object safe view on a `Solution`, so every day can live in one registry
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn example_answer(&self, part: Part) -> Option<&'static str>;
+
+    /// Names of the registered variants of a part, without `MAIN_VARIANT`
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+
+    fn run(&self, input: &str, kind: InputKind, part: Part) -> PartResult;
+
+    /// Runs a registered variant, or the part itself for `MAIN_VARIANT`. `None` if there is no such variant.
+    fn run_variant(&self, input: &str, kind: InputKind, part: Part, variant: &str) -> Option<PartResult>;
+}
+
+fn find_variant<S, A>(main: SolveFn<S, A>, variants: Vec<Variant<S, A>>, name: &str) -> Option<SolveFn<S, A>>
+where S: Solution {
+    if name == MAIN_VARIANT {
+        return Some(main);
+    }
+    variants.into_iter().find(|variant| variant.name == name).map(|variant| variant.solve)
+}
+
+fn timed<S, A>(input: &str, kind: InputKind, solve: SolveFn<S, A>) -> PartResult
+where S: Solution, A: Display {
+    let now = Instant::now();
+    let parsed = match kind {
+        InputKind::Puzzle => S::parse(input),
+        InputKind::Example => S::parse_example(input),
+    };
+    let parse_time = now.elapsed();
+
+    let now = Instant::now();
+    let answer = solve(&parsed).to_string();
+    let solve_time = now.elapsed();
+
+    PartResult { answer, parse_time, solve_time }
+}
+
+impl<S> DynSolution for S
+where S: Solution + Sync {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn example_answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => S::EXAMPLE_ONE,
+            Part::Two => S::EXAMPLE_TWO,
+        }
+    }
+
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        match part {
+            Part::One => S::part_one_variants().iter().map(|variant| variant.name).collect(),
+            Part::Two => S::part_two_variants().iter().map(|variant| variant.name).collect(),
+        }
+    }
+
+    fn run(&self, input: &str, kind: InputKind, part: Part) -> PartResult {
+        self.run_variant(input, kind, part, MAIN_VARIANT).expect("every part has a main variant")
+    }
+
+    fn run_variant(&self, input: &str, kind: InputKind, part: Part, variant: &str) -> Option<PartResult> {
+        match part {
+            Part::One => Some(timed::<S, _>(input, kind, find_variant::<S, _>(S::part_one, S::part_one_variants(), variant)?)),
+            Part::Two => Some(timed::<S, _>(input, kind, find_variant::<S, _>(S::part_two, S::part_two_variants(), variant)?)),
+        }
+    }
+}
+
+/// Every solution of the crate, looked up by (year, day)
+pub struct Registry {
+    solutions: &'static [&'static dyn DynSolution]
+}
+
+impl Registry {
+    pub const fn new(solutions: &'static [&'static dyn DynSolution]) -> Registry {
+        Registry { solutions }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+        self.solutions
+            .iter()
+            .find(|solution| solution.year() == year && solution.day() == day)
+            .copied()
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.iter().map(|solution| solution.year()).collect();
+        years.sort_unstable();
+        years.dedup();
+        years
+    }
+
+    /// All solutions of a year, sorted by day
+    pub fn year(&self, year: u16) -> Vec<&'static dyn DynSolution> {
+        let mut solutions: Vec<&'static dyn DynSolution> = self.solutions
+            .iter()
+            .filter(|solution| solution.year() == year)
+            .copied()
+            .collect();
+        solutions.sort_by_key(|solution| solution.day());
+        solutions
+    }
+
+    /// All solutions, sorted by year and day
+    pub fn all(&self) -> Vec<&'static dyn DynSolution> {
+        let mut solutions = self.solutions.to_vec();
+        solutions.sort_by_key(|solution| (solution.year(), solution.day()));
+        solutions
+    }
+}
@@ -1,6 +1,5 @@
-use std::time::SystemTime;
 use itertools::Itertools;
-use advent_of_code_2024::utils;
+use crate::solution::{Solution, Variant};
 
 fn part_one(input: &str) -> i32 {
     let mut first_location_ids = Vec::new();
@@ -100,37 +99,37 @@ fn part_two_v2(input: &str) -> i32 {
     similarity_score
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = utils::read_input(1);
-
-    let now = SystemTime::now();
-    println!("Part One: {}", part_one(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-
-    let now = SystemTime::now();
-    println!("Part One V2: {}", part_one_v2(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-
-    let now = SystemTime::now();
-    println!("Part Two: {}", part_two(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-
-    let now = SystemTime::now();
-    println!("Part Two V2: {}", part_two_v2(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-
-    Ok(())
 
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("11");
+    const EXAMPLE_TWO: Option<&'static str> = Some("31");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+
+    fn part_one_variants() -> Vec<Variant<Self, i32>> {
+        vec![Variant { name: "v2", solve: |input| part_one_v2(input) }]
+    }
+
+    fn part_two_variants() -> Vec<Variant<Self, i32>> {
+        vec![Variant { name: "v2", solve: |input| part_two_v2(input) }]
+    }
 }
@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+use itertools::Itertools;
+use crate::solution::Solution;
+
+fn is_sorted_ascending<T>(data: &[T]) -> Option<bool>
+    where T: Ord {
+    if data.is_empty() {
+        return None;
+    }
+    // take windows of 2 and check if every item after the current is greater or equal than current
+    Some(data.windows(2).all(|w| w[0] <= w[1]))
+}
+
+fn is_sorted_descending<T>(data: &[T]) -> Option<bool>
+where T: Ord {
+    if data.is_empty() {
+        return None;
+    }
+    // take windows of 2 and check if every item after the current is greater or equal than current
+    Some(data.windows(2).all(|w| w[0] >= w[1]))
+}
+
+fn is_valid_next_level(first: i32, second: i32) -> bool {
+    ((second - first).abs() >= 1) && ((second - first).abs() <= 3)
+}
+
+fn is_safe(numbers: &[i32]) -> bool {
+    if numbers.is_empty() {
+        return false;
+    }
+    let mut prev = numbers[0];
+    for (i, curr) in numbers.iter().enumerate() {
+        if i == 0 {
+            prev = *curr;
+            continue;
+        }
+        if !is_valid_next_level(prev, *curr) {
+            return false;
+        }
+        prev = *curr;
+    }
+    true
+}
+
+fn is_valid(numbers: &[i32]) -> bool {
+    if !numbers.iter().duplicates().collect_vec().is_empty() {
+        // no duplicates allowed, only strictly ascending or descending
+        return false;
+    }
+
+    let ordering = if is_sorted_ascending(numbers).expect("oh no") {
+        Ordering::Greater
+    }
+    else if is_sorted_descending(numbers).expect("oh no") {
+        Ordering::Less
+    }
+    else {
+        return false;
+    };
+
+    match ordering {
+        Ordering::Greater => is_safe(numbers),
+        Ordering::Less => is_safe(numbers),
+        Ordering::Equal => false
+    }
+}
+
+fn part_one(input: &str) -> i32 {
+    let mut safe_count = 0;
+    for line in input.lines() {
+        let numbers: Vec<i32> = line
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .iter()
+            .map(|num| num.parse::<i32>().unwrap())
+            .collect();
+
+        if is_valid(&numbers) {
+            safe_count += 1;
+        }
+    }
+    safe_count
+}
+
+fn part_two(input: &str) -> i32 {
+    let mut safe_count = 0;
+    for line in input.lines() {
+        let numbers: Vec<i32> = line
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .iter()
+            .map(|num| num.parse::<i32>().unwrap())
+            .collect();
+
+        if is_valid(&numbers) {
+            safe_count += 1;
+        }
+        else {
+            let mut valid_combi = false;
+            for combi in numbers.iter().copied().combinations(numbers.len() - 1) {
+                if is_valid(&combi) {
+                   valid_combi = true;
+                }
+            }
+            if valid_combi {
+                safe_count += 1;
+            }
+        }
+    }
+    safe_count
+}
+
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("2");
+    const EXAMPLE_TWO: Option<&'static str> = Some("4");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
@@ -1,24 +1,21 @@
-use std::time::SystemTime;
-use advent_of_code_2024::utils;
 use regex::Regex;
+use crate::solution::Solution;
 
 fn get_mul_result(operation: &str) -> i32 {
     let mut numbers: Vec<String> = Vec::new();
     let mut number = String::from("");
     for char in operation.chars() {
-        if char.is_digit(10) {
+        if char.is_ascii_digit() {
             number.push(char);
-        } else {
-            if !number.is_empty() {
-                numbers.push(number.clone());
-                number = String::from("");
-            }
+        } else if !number.is_empty() {
+            numbers.push(number.clone());
+            number = String::from("");
         }
     }
     numbers
         .iter()
         .map(|num| num.parse::<i32>().unwrap())
-        .fold(1, |acc, num| acc * num) // 1 * num1 * num2
+        .product() // num1 * num2
 }
 
 
@@ -57,44 +54,48 @@ fn part_two(input: &str) -> i32 {
         .collect();
 
     let mut enabled = true; // from the start it is true
-    for (i, char) in input.chars().enumerate() {
+    for (i, _) in input.chars().enumerate() {
         if disables_locations.contains(&i) {
             enabled = false;
         }
         else if enables_locations.contains(&i) {
             enabled = true;
         }
-        else if operations.iter().any(|(pos, operation)| *pos == i) && enabled { // if we are currently on a mul operation, and enabled
+        else if operations.iter().any(|(pos, _)| *pos == i) && enabled { // if we are currently on a mul operation, and enabled
             let operation = operations
                 .iter()
-                .filter(|(pos, operation)| *pos == i)
-                .map(|(pos, operation)| operation)
+                .filter(|(pos, _)| *pos == i)
+                .map(|(_, operation)| operation)
                 .collect::<Vec<&&str>>();
-            answer += get_mul_result(operation.get(0).unwrap());
+            answer += get_mul_result(operation.first().unwrap());
         }
     }
     answer
 }
 
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = utils::read_input(3);
-    let example_input_v1 = utils::read_input_from_path("C:\\Documenten\\magic-repo\\advent_of_code_2024\\example_input\\day03_v1.txt");
-    let example_input_v2 = utils::read_input_from_path("C:\\Documenten\\magic-repo\\advent_of_code_2024\\example_input\\day03_v2.txt");
+pub struct Day03;
 
-    let now = SystemTime::now();
-    println!("Part One: {}", part_one(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
 
-    let now = SystemTime::now();
-    println!("Part two: {}", part_two(&input));
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
+    type Input<'a> = &'a str;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
 
-    Ok(())
-}
\ No newline at end of file
+    const EXAMPLE_ONE: Option<&'static str> = Some("161");
+    const EXAMPLE_TWO: Option<&'static str> = Some("48");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use crate::solution::{Solution, Variant};
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::search;
+use crate::utils::search::Pattern;
+
+fn get_targets_at(row: usize, col: usize, grid: &Grid<char>, target: &str) -> i32 {
+    // try every direction from location (row, col)
+    Direction::ALL
+        .iter()
+        .filter(|&&direction| search::word_at(grid, (row, col), direction, target))
+        .count() as i32
+}
+
+fn part_one(grid: &Grid<char>) -> i32 {
+    (0..grid.height())
+        .into_par_iter() // PARALLEL POWERRR
+        .map(|i| (0..grid.width()).map(|j| get_targets_at(i, j, grid, "XMAS")).sum::<i32>())
+        .sum()
+}
+
+fn part_one_more_overhead(grid: &Grid<char>) -> i32 {
+    let arc_found_xmases = Arc::new(Mutex::new(0));
+    (0..grid.height()).into_par_iter().for_each(|i| { // a lock per cell instead of one sum per row
+        let captured_found_xmases = Arc::clone(&arc_found_xmases);
+        for j in 0..grid.width() {
+            let found = get_targets_at(i, j, grid, "XMAS");
+            *captured_found_xmases.lock().unwrap() += found;
+        }
+    });
+
+    let found_xmases = *arc_found_xmases.lock().unwrap();
+    found_xmases
+}
+
+fn part_one_not_par(grid: &Grid<char>) -> i32 {
+    search::find_word(grid, "XMAS", &Direction::ALL).len() as i32
+}
+
+fn part_two(grid: &Grid<char>) -> i32 {
+    // m's on the left, s's on the right, the rotations cover the other three sides
+    let x_mas = Pattern::parse("M.S\n.A.\nM.S", '.').unwrap();
+    x_mas.find_rotated(grid).len() as i32
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<char>;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("18");
+    const EXAMPLE_TWO: Option<&'static str> = Some("9");
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse_chars(input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part_one(grid: &Grid<char>) -> i32 {
+        part_one(grid)
+    }
+
+    fn part_two(grid: &Grid<char>) -> i32 {
+        part_two(grid)
+    }
+
+    fn part_one_variants() -> Vec<Variant<Self, i32>> {
+        vec![
+            Variant { name: "more_overhead", solve: |grid| part_one_more_overhead(grid) },
+            Variant { name: "not_par", solve: |grid| part_one_not_par(grid) },
+        ]
+    }
+}
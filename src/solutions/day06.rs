@@ -0,0 +1,319 @@
+use std::fmt::{Display, Formatter};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use crate::solution::{Solution, Variant};
+use crate::utils::bitgrid::BitGrid;
+use crate::utils::direction::Direction;
+use crate::utils::grid::{Grid, GridError, Position};
+use crate::utils::image::Rgb;
+use crate::utils::point::Point2D;
+
+const OBSTACLE: char = '#';
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LabError {
+    Grid(GridError),
+    /// A cell that is not `.`, `#` or `^`
+    UnknownCell { position: Position, cell: char },
+    NoGuard
+}
+
+impl Display for LabError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LabError::Grid(err) => write!(f, "{}", err),
+            LabError::UnknownCell { position: (row, col), cell } => {
+                write!(f, "line {}, column {}: '{}' is not a lab cell", row + 1, col + 1, cell)
+            }
+            LabError::NoGuard => write!(f, "there is no guard '^' in the lab"),
+        }
+    }
+}
+
+impl std::error::Error for LabError {}
+
+/// The lab with, for every cell and direction, where the guard stops in front of the next obstacle,
+/// so the guard jumps from turn to turn instead of walking cell by cell
+pub struct Lab {
+    grid: Grid<char>,
+    start: Position,
+    /// Indexed like `Direction::ORTHOGONAL`, `None` where the guard walks out of the grid instead
+    stops: Grid<[Option<Position>; 4]>
+}
+
+impl Lab {
+    pub fn parse(input: &str) -> Result<Lab, LabError> {
+        let grid = Grid::parse_chars(input).map_err(LabError::Grid)?;
+        if let Some((position, &cell)) = grid.iter().find(|(_, cell)| !matches!(cell, '.' | '#' | '^')) {
+            return Err(LabError::UnknownCell { position, cell });
+        }
+        let start = grid.find(|&char| char == '^').ok_or(LabError::NoGuard)?;
+        let stops = stop_table(&grid);
+        Ok(Lab { grid, start, stops })
+    }
+
+    /// Where the guard walking `direction` from `position` turns, `None` if it leaves the grid
+    pub fn next_stop(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.stops[position][slot(direction)]
+    }
+
+    /// Like `next_stop` with an extra obstacle placed in the lab
+    fn next_stop_with(&self, position: Position, direction: Direction, obstacle: Position) -> Option<Position> {
+        let stop = self.next_stop(position, direction);
+        let blocked = match (steps_ahead(position, obstacle, direction), stop) {
+            (Some(0) | None, _) => false,
+            (Some(_), None) => true,
+            (Some(to_obstacle), Some(stop)) => steps_ahead(position, stop, direction).is_some_and(|to_stop| to_obstacle <= to_stop),
+        };
+        match blocked {
+            true => self.grid.step(obstacle, direction.opposite()),
+            false => stop,
+        }
+    }
+
+    /// Jumps the guard from turn to turn, calling `segment` with the start, the stop (`None` at the edge)
+    /// and the direction of every straight stretch. `false` if the guard walks in a loop instead of leaving.
+    fn walk(&self, obstacle: Option<Position>, mut segment: impl FnMut(Position, Option<Position>, Direction)) -> bool {
+        let mut turns = BitGrid::with_directions(self.grid.width(), self.grid.height());
+        let (mut position, mut direction) = (self.start, Direction::North);
+        loop {
+            let stop = match obstacle {
+                Some(obstacle) => self.next_stop_with(position, direction, obstacle),
+                None => self.next_stop(position, direction),
+            };
+            segment(position, stop, direction);
+            let Some(stop) = stop else {
+                return true;
+            };
+            // a second turn here coming from the same direction means the guard goes round in circles
+            if !turns.insert_directed(stop, direction) {
+                return false;
+            }
+            position = stop;
+            direction = direction.rotate_clockwise();
+        }
+    }
+
+    /// Every cell the guard walks before leaving the grid, `None` if it walks in a loop forever instead
+    pub fn visited(&self) -> Option<BitGrid> {
+        let mut visited = BitGrid::for_grid(&self.grid);
+        let leaves = self.walk(None, |from, to, direction| {
+            for (position, _) in self.grid.ray(from, direction) {
+                visited.insert(position);
+                if Some(position) == to {
+                    break;
+                }
+            }
+        });
+        leaves.then_some(visited)
+    }
+
+    /// Whether an extra obstacle at `obstacle` traps the guard in a loop. There is no room for one
+    /// on the guard's start or on an existing obstacle.
+    pub fn loops_with_obstacle(&self, obstacle: Position) -> bool {
+        obstacle != self.start && self.grid[obstacle] != OBSTACLE && !self.walk(Some(obstacle), |_, _, _| {})
+    }
+
+    /// The lab with the guard's route drawn in like the puzzle text: `|` and `-` where it walks
+    /// up/down or sideways, `+` where it does both
+    pub fn render_path(&self) -> Grid<char> {
+        self.draw_path(|_| {})
+    }
+
+    /// Like `render_path`, calling `frame` with the drawing so far after every straight stretch
+    pub fn draw_path(&self, mut frame: impl FnMut(&Grid<char>)) -> Grid<char> {
+        let mut drawing = self.grid.clone();
+        self.walk(None, |from, to, direction| {
+            let mark = match direction {
+                Direction::North | Direction::South => '|',
+                _ => '-',
+            };
+            for (position, _) in self.grid.ray(from, direction) {
+                // the start keeps its `^`
+                if position != self.start {
+                    drawing[position] = match drawing[position] {
+                        '.' => mark,
+                        drawn if drawn == mark => mark,
+                        _ => '+',
+                    };
+                }
+                if Some(position) == to {
+                    break;
+                }
+            }
+            frame(&drawing);
+        });
+        drawing
+    }
+}
+
+/// Colors of the cells of `Lab::render_path`
+pub fn path_color(cell: &char) -> Rgb {
+    match cell {
+        '#' => [90, 90, 110],
+        '^' => [230, 60, 60],
+        '|' | '-' => [240, 200, 60],
+        '+' => [250, 130, 40],
+        _ => [20, 20, 30],
+    }
+}
+
+fn slot(direction: Direction) -> usize {
+    Direction::ORTHOGONAL.iter().position(|&orthogonal| orthogonal == direction).expect("the guard only walks orthogonally")
+}
+
+/// How many steps `to` lies straight ahead of `from` in `direction`
+fn steps_ahead((row, col): Position, (to_row, to_col): Position, direction: Direction) -> Option<usize> {
+    match direction {
+        Direction::North if col == to_col && to_row <= row => Some(row - to_row),
+        Direction::South if col == to_col && to_row >= row => Some(to_row - row),
+        Direction::West if row == to_row && to_col <= col => Some(col - to_col),
+        Direction::East if row == to_row && to_col >= col => Some(to_col - col),
+        _ => None,
+    }
+}
+
+fn stop_table(grid: &Grid<char>) -> Grid<[Option<Position>; 4]> {
+    let mut stops = Grid::new(grid.width(), grid.height(), [None; 4]);
+    for (slot, &direction) in Direction::ORTHOGONAL.iter().enumerate() {
+        // the cell ahead has to be done first: row by row going north or west, backwards going east or south
+        let mut positions: Vec<Position> = grid.positions().collect();
+        if matches!(direction, Direction::East | Direction::South) {
+            positions.reverse();
+        }
+        for position in positions {
+            let stop = match grid.step(position, direction) {
+                None => None,
+                Some(ahead) if grid[ahead] == OBSTACLE => Some(position),
+                Some(ahead) => stops[ahead][slot],
+            };
+            stops[position][slot] = stop;
+        }
+    }
+    stops
+}
+
+/// Returns only the first found mark
+fn is_within_bounds(grid: &Grid<char>, point: &Point2D<i64>) -> bool {
+    grid.point_position(*point).is_some()
+}
+
+fn get_coordinates_until(grid: &Grid<char>, curr_pos: &Point2D<i64>, direction: &Direction, stop_sign: char) -> Vec<Point2D<i64>> {
+    let mut walked_coordinates: Vec<Point2D<i64>> = Vec::new();
+    let mut pos = *curr_pos;
+    walked_coordinates.push(pos); // or not?
+    while grid.get_point(pos) != Some(&stop_sign) {
+        pos = pos.step(*direction);
+        walked_coordinates.push(pos);
+        if !is_within_bounds(grid, &pos) {
+            break; // crashes if next while iteration is out of bounds
+        }
+    }
+    walked_coordinates.remove(walked_coordinates.len() - 1); // remove coordinate on stop sign
+    walked_coordinates
+}
+
+fn will_go_out_of_bounds(grid: &Grid<char>, curr_pos: &Point2D<i64>, direction: &Direction) -> bool {
+    !is_within_bounds(grid, &curr_pos.step(*direction))
+}
+
+/// Walks the guard from `start` until it leaves the grid and returns every cell it walked,
+/// `None` if it walks in a loop forever instead
+fn walk_guard(grid: &Grid<char>, start: Point2D<i64>, stop_sign: char) -> Option<BitGrid> {
+    let mut curr_direction = Direction::North;
+    let mut curr_pos = start;
+    let mut visited = BitGrid::for_grid(grid);
+    let mut turns = BitGrid::with_directions(grid.width(), grid.height());
+
+    loop {
+        let walked_coordinates_in_direction = get_coordinates_until(grid, &curr_pos, &curr_direction, stop_sign);
+        for coordinate in &walked_coordinates_in_direction {
+            visited.insert(grid.point_position(*coordinate).unwrap());
+        }
+        curr_pos = *walked_coordinates_in_direction.last().unwrap();
+        if will_go_out_of_bounds(grid, &curr_pos, &curr_direction) {
+            return Some(visited);
+        }
+        // turning at the same spot in the same direction as before, from here on the walk repeats itself
+        if !turns.insert_directed(grid.point_position(curr_pos).unwrap(), curr_direction) {
+            return None;
+        }
+        curr_direction = curr_direction.rotate_clockwise();
+    }
+}
+
+fn part_one(lab: &Lab) -> i32 {
+    lab.visited().expect("the guard walks out of the grid").count() as i32
+}
+
+fn part_two(lab: &Lab) -> i32 {
+    // an obstruction off the path is never walked into
+    let candidates: Vec<Position> = lab.visited().expect("the guard walks out of the grid").positions().collect();
+    candidates
+        .into_par_iter()
+        .filter(|&position| lab.loops_with_obstacle(position))
+        .count() as i32
+}
+
+fn part_one_walk(lab: &Lab) -> i32 {
+    let start = Point2D::from_position(lab.start);
+
+    // walked out of grid, count distinct coordinates
+    walk_guard(&lab.grid, start, OBSTACLE).expect("the guard walks out of the grid").count() as i32
+}
+
+fn part_two_walk(lab: &Lab) -> i32 {
+    let grid = &lab.grid;
+    let start = Point2D::from_position(lab.start);
+    let start_position = lab.start;
+
+    // an obstruction off the path is never walked into, and the guard would notice one at the start
+    let candidates: Vec<Position> = walk_guard(grid, start, OBSTACLE)
+        .expect("the guard walks out of the grid")
+        .positions()
+        .filter(|&position| position != start_position)
+        .collect();
+
+    candidates
+        .into_par_iter()
+        .filter(|&position| {
+            let mut obstructed_grid = grid.clone();
+            obstructed_grid[position] = OBSTACLE;
+            walk_guard(&obstructed_grid, start, OBSTACLE).is_none()
+        })
+        .count() as i32
+}
+
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Lab;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("41");
+    const EXAMPLE_TWO: Option<&'static str> = Some("6");
+
+    fn parse(input: &str) -> Lab {
+        Lab::parse(input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part_one(lab: &Lab) -> i32 {
+        part_one(lab)
+    }
+
+    fn part_two(lab: &Lab) -> i32 {
+        part_two(lab)
+    }
+
+    fn part_one_variants() -> Vec<Variant<Self, i32>> {
+        vec![Variant { name: "walk", solve: |lab| part_one_walk(lab) }]
+    }
+
+    fn part_two_variants() -> Vec<Variant<Self, i32>> {
+        vec![Variant { name: "walk", solve: |lab| part_two_walk(lab) }]
+    }
+}
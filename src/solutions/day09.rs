@@ -0,0 +1,95 @@
+use crate::solution::Solution;
+
+
+fn get_memory_map(disk_map: &[i32]) -> Vec<Option<i32>> {
+    let mut memory_layout = Vec::new();
+    let mut file_id = 0;
+    for (i, item) in disk_map.iter().enumerate() {
+        if i % 2 == 0 { // push file
+            for _ in 0..*item {
+                memory_layout.push(Some(file_id));
+            }
+            file_id += 1;
+        }
+        else { // push 'empty space'
+            for _ in 0..*item {
+                memory_layout.push(None);
+            }
+        }
+    }
+    memory_layout
+}
+
+fn rearrange_memory(memory: &[Option<i32>]) -> Vec<Option<i32>> {
+    let mut rearranged_memory = memory.to_vec();
+    // spots before it are all filled, and only get filled further, so it only moves forward
+    let mut first_empty_spot = 0;
+    for i in (0..memory.len()).rev() {
+        if let Some(value) = memory[i] {
+            while first_empty_spot < i && rearranged_memory[first_empty_spot].is_some() {
+                first_empty_spot += 1;
+            }
+            if first_empty_spot >= i {
+                break; // everything until now is Some(val), that means rearranged
+            }
+            rearranged_memory[first_empty_spot] = Some(value);
+            rearranged_memory[i] = None;
+        }
+    }
+    rearranged_memory
+}
+
+fn calculate_memory_checksum(memory: &[Option<i32>]) -> i64 {
+    memory
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, item)| {
+            match item {
+                Some(value) => {
+                    acc + i as i64 * *value as i64
+                },
+                None => acc
+            }
+        })
+}
+
+fn part_one(input: &str) -> i64 {
+    let disk_map = input
+        .chars()
+        .filter_map(|c| c.to_string().parse::<i32>().ok())
+        .collect::<Vec<i32>>();
+
+    let memory_map = get_memory_map(&disk_map);
+    let rearranged_memory = rearrange_memory(&memory_map);
+    calculate_memory_checksum(&rearranged_memory)
+}
+
+fn part_two(_input: &str) -> i32 {
+    todo!()
+}
+
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i64;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("1928");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
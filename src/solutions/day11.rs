@@ -0,0 +1,65 @@
+use crate::solution::Solution;
+use crate::utils::memo::Memo;
+
+/// Stones with an even number of digits split into their left and right half
+fn split_stone(stone: u64) -> Option<(u64, u64)> {
+    let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+    if !digits.is_multiple_of(2) {
+        return None;
+    }
+    let half = 10u64.pow(digits / 2);
+    Some((stone / half, stone % half))
+}
+
+/// Number of stones a single stone turns into, the order of the stones does not matter
+fn count_stones(count: &mut dyn FnMut((u64, i32)) -> i64, (stone, n_times): (u64, i32)) -> i64 {
+    if n_times == 0 {
+        return 1;
+    }
+    if stone == 0 {
+        return count((1, n_times - 1));
+    }
+    match split_stone(stone) {
+        Some((left, right)) => count((left, n_times - 1)) + count((right, n_times - 1)),
+        None => count((stone * 2024, n_times - 1)),
+    }
+}
+
+fn blink(line: &str, n_times: i32) -> i64 {
+    let mut memo = Memo::new();
+    line.split_whitespace()
+        .map(|stone| stone.parse::<u64>().unwrap_or_else(|err| panic!("invalid stone '{}': {}", stone, err)))
+        .map(|stone| memo.recurse((stone, n_times), &count_stones))
+        .sum()
+}
+
+fn part_one_and_two(input: &str, blink_count: i32) -> i64 {
+    let stone_line = String::from(input);
+    blink(&stone_line, blink_count)
+}
+
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("55312");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i64 {
+        part_one_and_two(input, 25)
+    }
+
+    fn part_two(input: &&str) -> i64 {
+        part_one_and_two(input, 75)
+    }
+}
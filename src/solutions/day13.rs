@@ -0,0 +1,102 @@
+use num::ToPrimitive;
+use crate::solution::Solution;
+use crate::utils::{linear, parse};
+use crate::utils::parse::ParseError;
+use crate::utils::point::Point2D;
+
+#[derive(Debug, Copy, Clone)]
+struct Button {
+    dx: i64,
+    dy: i64
+}
+
+impl Button {
+    fn from(x: i64, y: i64) -> Button {
+        Button { dx: x, dy: y }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ClawMachine {
+    a: Button,
+    b: Button,
+    prize: Point2D<i64>
+}
+
+fn collect_claw_machines(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let mut claw_machines: Vec<ClawMachine> = Vec::new();
+    for block in parse::blocks(input) {
+        // button a, button b, prize
+        block.expect_lines(3)?;
+        let numbers = block.parse_lines(parse::signed_ints_exact::<i64, 2>)?;
+        claw_machines.push(ClawMachine {
+            a: Button::from(numbers[0][0], numbers[0][1]),
+            b: Button::from(numbers[1][0], numbers[1][1]),
+            prize: Point2D::new(numbers[2][0], numbers[2][1])
+        });
+    }
+    Ok(claw_machines)
+}
+
+fn meets_criteria_part_one(button_a_count: i64, button_b_count: i64, push_limit: i64) -> bool {
+    (0..=push_limit).contains(&button_a_count) && (0..=push_limit).contains(&button_b_count)
+}
+
+fn meets_criteria_part_two(button_a_count: i64, button_b_count: i64, _push_limit: i64) -> bool {
+    button_a_count >= 0 && button_b_count >= 0
+}
+
+fn get_prize_within_reach(claw_machine: &ClawMachine, push_limit: i64, meets_criteria: fn(i64, i64, i64) -> bool) -> Option<(i64, i64)> {
+    // 94a + 22b = 8400 -> button_a_dx * unknown + button_b_dx * unknown = 8400
+    // 34a + 67b = 5400 -> button_a_dy * unknown + button_b_dy * unknown = 5400
+    // only whole button presses count, it was the "exactly" keyword that went wrong...
+    // parallel buttons give infinite solutions, the inputs have none of those
+    let matrix = [vec![claw_machine.a.dx, claw_machine.b.dx], vec![claw_machine.a.dy, claw_machine.b.dy]];
+    let counts = linear::solve_integers(&matrix, &[claw_machine.prize.x, claw_machine.prize.y]).integers()?;
+    let (button_a_count, button_b_count) = (counts[0].to_i64()?, counts[1].to_i64()?);
+    meets_criteria(button_a_count, button_b_count, push_limit).then_some((button_a_count, button_b_count))
+}
+
+fn part_one_and_two(claw_machines: &[ClawMachine], x_offset_part_x: i64, y_offset_part_x: i64) -> i64 {
+    let mut total_used_coins = 0;
+    let mut criteria_fn: fn(i64, i64, i64) -> bool = meets_criteria_part_one;
+    if x_offset_part_x > 0 { // part 2
+        criteria_fn = meets_criteria_part_two;
+    }
+    for claw_machine in claw_machines {
+        let prize = Point2D::new(claw_machine.prize.x + x_offset_part_x, claw_machine.prize.y + y_offset_part_x);
+        let claw_machine = ClawMachine { prize, ..*claw_machine };
+        if let Some((a_button_push_count, b_button_push_count)) = get_prize_within_reach(&claw_machine, 100, criteria_fn) {
+            total_used_coins += (a_button_push_count * 3) + b_button_push_count;
+        }
+    }
+    total_used_coins
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<ClawMachine>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("480");
+
+    fn parse(input: &str) -> Vec<ClawMachine> {
+        collect_claw_machines(input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part_one(claw_machines: &Vec<ClawMachine>) -> i64 {
+        let (x_offset_part_one, y_offset_part_one) = (0, 0);
+        part_one_and_two(claw_machines, x_offset_part_one, y_offset_part_one)
+    }
+
+    fn part_two(claw_machines: &Vec<ClawMachine>) -> i64 {
+        let (x_offset_part_two, y_offset_part_two) = (10000000000000, 10000000000000);
+        part_one_and_two(claw_machines, x_offset_part_two, y_offset_part_two)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+use crate::utils::{math, parse};
+use crate::utils::parse::ParseError;
+use crate::utils::point::Point2D;
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    position: Point2D<i32>,
+    velocity: Point2D<i32>
+}
+
+impl Robot {
+    fn new(position: Point2D<i32>, velocity: Point2D<i32>) -> Robot {
+        Robot { position, velocity }
+    }
+}
+
+/// The robots and the size of the room they walk around in, the example uses a smaller room than the puzzle
+pub struct Lobby {
+    robots: Vec<Robot>,
+    width: usize,
+    height: usize
+}
+
+fn collect_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse::lines(input, |line| {
+        // p=x,y v=dx,dy
+        let [x, y, dx, dy] = parse::signed_ints_exact::<i32, 4>(line)?;
+        Ok(Robot::new(Point2D::new(x, y), Point2D::new(dx, dy)))
+    })
+}
+
+fn walk_robot(robot: &Robot, width: usize, height: usize, amount_seconds: i32) -> Robot {
+    let mut updated_robot = *robot;
+    updated_robot.position += robot.velocity * amount_seconds;
+
+    updated_robot.position.x = math::modulo(updated_robot.position.x, width as i32);
+    updated_robot.position.y = math::modulo(updated_robot.position.y, height as i32);
+
+    updated_robot
+}
+
+fn count_robots(robots: &[Robot], width: usize, height: usize) -> Grid<i32> {
+    let mut tiles = Grid::new(width, height, 0);
+    for robot in robots {
+        tiles[(robot.position.y as usize, robot.position.x as usize)] += 1;
+    }
+    tiles
+}
+
+fn calculate_safety_score(tiles: &Grid<i32>) -> i32 {
+    // robots on the middle row or column are in no quadrant
+    let skip_row = tiles.height() / 2;
+    let skip_col = tiles.width() / 2;
+
+    let mut quadrant_counts = [0; 4]; // top left, top right, bottom left, bottom right
+    for ((row, col), robots_on_spot) in tiles.iter() {
+        if row == skip_row || col == skip_col {
+            continue;
+        }
+        let quadrant = 2 * (row > skip_row) as usize + (col > skip_col) as usize;
+        quadrant_counts[quadrant] += robots_on_spot;
+    }
+
+    quadrant_counts.iter().product()
+}
+
+fn parse_lobby(input: &str, width: usize, height: usize) -> Lobby {
+    let robots = collect_robots(input).unwrap_or_else(|err| panic!("{}", err));
+    Lobby { robots, width, height }
+}
+
+fn part_one(lobby: &Lobby) -> i32 {
+    let observe_x_seconds = 100;
+    let robots: Vec<Robot> = lobby.robots
+        .iter()
+        .map(|robot| walk_robot(robot, lobby.width, lobby.height, observe_x_seconds))
+        .collect();
+
+    calculate_safety_score(&count_robots(&robots, lobby.width, lobby.height))
+}
+
+fn part_two(_lobby: &Lobby) -> i32 {
+    0
+}
+
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Lobby;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("12");
+
+    fn parse(input: &str) -> Lobby {
+        parse_lobby(input, 101, 103)
+    }
+
+    fn parse_example(input: &str) -> Lobby {
+        parse_lobby(input, 11, 7)
+    }
+
+    fn part_one(lobby: &Lobby) -> i32 {
+        part_one(lobby)
+    }
+
+    fn part_two(lobby: &Lobby) -> i32 {
+        part_two(lobby)
+    }
+}
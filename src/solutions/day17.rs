@@ -1,5 +1,4 @@
-use std::time::SystemTime;
-use advent_of_code_2024::utils;
+use crate::solution::Solution;
 
 fn adv (combo_op: i32, reg_a: i32) -> i32 {
     // The adv instruction (opcode 0) performs division.
@@ -15,7 +14,7 @@ fn bxl (literal_op: i32, reg_b: i32) -> i32 {
     // The bxl instruction (opcode 1) calculates the bitwise XOR of register B and the instruction's literal operand,
     // then stores the result in register B.
 
-    return literal_op ^ reg_b
+    literal_op ^ reg_b
 }
 
 fn bst (combo_op: i32) -> i32 {
@@ -34,7 +33,7 @@ fn jnz (literal_op: i32, reg_a: i32, instruction_ptr: usize) -> usize {
     if reg_a != 0 {
         return literal_op as usize;
     }
-    return instruction_ptr + 2;
+    instruction_ptr + 2
 }
 
 fn bxc (reg_b: i32, reg_c: i32) -> i32 {
@@ -116,7 +115,7 @@ fn perform_instruction(opcode: i32,
     }
 }
 
-fn part_one(input: &str) {
+fn part_one(input: &str) -> String {
     let mut reg_a = 0;
     let mut reg_b = 0;
     let mut reg_c = 0;
@@ -126,13 +125,13 @@ fn part_one(input: &str) {
 
     for line in input.lines() {
         if line.contains("A") {
-            reg_a = line.split(':').last().unwrap().trim().parse::<i32>().unwrap();
+            reg_a = line.split(':').next_back().unwrap().trim().parse::<i32>().unwrap();
         }
         else if line.contains("B") {
-            reg_b = line.split(':').last().unwrap().trim().parse::<i32>().unwrap();
+            reg_b = line.split(':').next_back().unwrap().trim().parse::<i32>().unwrap();
         }
         else if line.contains("C") {
-            reg_c = line.split(':').last().unwrap().trim().parse::<i32>().unwrap();
+            reg_c = line.split(':').next_back().unwrap().trim().parse::<i32>().unwrap();
         }
         else if line.contains("Program") {
             program = line
@@ -145,8 +144,6 @@ fn part_one(input: &str) {
         }
     }
 
-    println!("A: {}, B: {}, C: {}, Program: {:?}", reg_a, reg_b, reg_c, program);
-
     let mut program_output = Vec::new();
     while instruction_ptr < program.len() {
         if instruction_ptr == program.len() - 1 {
@@ -164,40 +161,38 @@ fn part_one(input: &str) {
                                                               &mut reg_a,
                                                               &mut reg_b,
                                                               &mut reg_c) {
-            println!("Got output: {}", instruction_result);
             program_output.push(instruction_result);
         }
-
-        println!("A: {}, B: {}, C: {}", reg_a, reg_b, reg_c);
-        println!("{}", instruction_ptr);
     }
-    println!("Program output: {:?}", program_output)
+    program_output.iter().map(|output| output.to_string()).collect::<Vec<String>>().join(",")
 }
 
-fn part_two(input: &str) -> i32 {
+fn part_two(_input: &str) -> i32 {
     todo!()
 }
 
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = utils::read_input(17);
-    let example_input = utils::read_input_from_path("C:\\Documenten\\magic-repo\\advent_of_code_2024\\example_input\\day17.txt");
-
-    let now = SystemTime::now();
-    let ans = part_one(&input);
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-    // println!("Part One: {}", ans);
-
-    let now = SystemTime::now();
-    // let ans_2 = part_two(&input);
-    println!("Elapsed time as:\n    Seconds: {} \n    Milliseconds: {}\n    Microseconds: {}\n",
-             now.elapsed()?.as_secs(),
-             now.elapsed()?.as_millis(),
-             now.elapsed()?.as_micros());
-    // println!("Part One V2: {}", ans_2);
-
-    Ok(())
-}
\ No newline at end of file
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = String;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("4,6,3,5,6,3,5,2,1,0");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
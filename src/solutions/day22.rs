@@ -0,0 +1,59 @@
+use crate::solution::Solution;
+
+fn calculate_next_secret_num(num: i64) -> i64 {
+    // mixing: xor with num * 64, pruning, modulo 16777216
+    let step_one = (num ^ (num * 64)) % 16777216;
+    let step_two = ((step_one as f64 / 32.0).floor() as i64 ^ step_one) % 16777216;
+    ((step_two * 2048) ^ step_two) % 16777216
+}
+
+
+fn part_one(input: &str) -> i64 {
+    let nums = input
+        .lines()
+        .filter_map(|l| l.parse::<i64>().ok())
+        .collect::<Vec<i64>>();
+
+    let after_2000: Vec<i64> = nums
+        .iter()
+        .map(|num| {
+            let mut num_after_2000 = *num;
+            for _ in 0..2000 {
+                num_after_2000 = calculate_next_secret_num(num_after_2000);
+            }
+            num_after_2000
+        })
+        .collect();
+
+    after_2000.iter().sum()
+}
+
+fn part_two(_input: &str) -> i32 {
+    todo!()
+}
+
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i64;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("37327623");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
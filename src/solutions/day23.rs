@@ -0,0 +1,55 @@
+use itertools::Itertools;
+use crate::solution::Solution;
+use crate::utils::graph::Graph;
+
+fn build_network(input: &str) -> Graph {
+    Graph::undirected_from_edges(input
+        .lines()
+        .map(|line| line.split('-').collect_tuple::<(&str, &str)>().unwrap()))
+}
+
+fn part_one(network: &Graph) -> i32 {
+    // only sets with a pc whose name starts with t, a 't' anywhere in the name was too high
+    network
+        .triangles()
+        .iter()
+        .filter(|triangle| triangle.iter().any(|&pc| network.name(pc).starts_with('t')))
+        .count() as i32
+}
+
+fn part_two(network: &Graph) -> String {
+    // the password is the largest set of pcs that are all connected, sorted by name
+    network
+        .maximum_clique()
+        .iter()
+        .map(|&pc| network.name(pc))
+        .sorted()
+        .join(",")
+}
+
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Graph;
+    type AnswerOne = i32;
+    type AnswerTwo = String;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("7");
+    const EXAMPLE_TWO: Option<&'static str> = Some("co,de,ka,ta");
+
+    fn parse(input: &str) -> Graph {
+        build_network(input)
+    }
+
+    fn part_one(network: &Graph) -> i32 {
+        part_one(network)
+    }
+
+    fn part_two(network: &Graph) -> String {
+        part_two(network)
+    }
+}
@@ -0,0 +1,156 @@
+use itertools::Itertools;
+use num::rational::Ratio;
+use num::Signed;
+use crate::utils::{linear, parse};
+use crate::utils::parse::ParseError;
+use crate::utils::linear::Solutions;
+use crate::solution::Solution;
+
+#[derive(Debug, Copy, Clone)]
+struct Position {
+    x: i64,
+    y: i64,
+    // only part two looks at the paths in 3d, which is not solved yet
+    #[allow(dead_code)]
+    z: i64
+}
+
+impl Position {
+    fn new(x: i64, y: i64, z: i64) -> Position {
+        Position { x, y, z }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Velocity {
+    /// Velocity in xyz axis
+    x: i64,
+    y: i64,
+    #[allow(dead_code)]
+    z: i64
+}
+
+impl Velocity {
+    fn new(x: i64, y: i64, z: i64) -> Velocity {
+        Velocity { x, y, z }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct HailStone {
+    position: Position,
+    velocity: Velocity
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Area {
+    min_x: i64,
+    min_y: i64,
+    max_x: i64,
+    max_y: i64
+}
+
+impl Area {
+    fn new(min_x: i64, min_y: i64, max_x: i64, max_y: i64) -> Area {
+        Area { min_x, min_y, max_x, max_y }
+    }
+}
+
+/// The hailstones and the area their paths have to cross in, the example uses a much smaller area
+pub struct Hailstorm {
+    hail_stones: Vec<HailStone>,
+    test_area: Area
+}
+
+fn get_hailstone_from_line(line: &str) -> Result<HailStone, ParseError> {
+    // px, py, pz @ vx, vy, vz
+    let [px, py, pz, vx, vy, vz] = parse::signed_ints_exact::<i64, 6>(line)?;
+    let position = Position::new(px, py, pz);
+    let velocity = Velocity::new(vx, vy, vz);
+    Ok(HailStone { position, velocity })
+}
+
+/// The nanoseconds each hailstone needs to get to where their paths cross in the xy plane, ignoring z.
+/// Negative times are in the past, `None` if the paths are parallel or the same.
+fn calculate_hailstones_intersection_times(hail_stone_a: &HailStone, hail_stone_b: &HailStone) -> Option<Vec<Ratio<i128>>> {
+    // position_a + t * velocity_a = position_b + s * velocity_b, for x and y
+    let matrix = [
+        vec![hail_stone_a.velocity.x, -hail_stone_b.velocity.x],
+        vec![hail_stone_a.velocity.y, -hail_stone_b.velocity.y]
+    ];
+    let rhs = [hail_stone_b.position.x - hail_stone_a.position.x, hail_stone_b.position.y - hail_stone_a.position.y];
+    // the determinants of two equations of i64 values fit in an i128
+    match linear::solve_i128(&matrix, &rhs).expect("two hailstone equations fit in i128") {
+        Solutions::Unique(times) => Some(times),
+        Solutions::None | Solutions::Infinite { .. } => None,
+    }
+}
+
+/// Whether `position + velocity * time` lies in `min..=max`, compared over the positive denominator of `time`
+fn is_within(position: i64, velocity: i64, time: &Ratio<i128>, min: i64, max: i64) -> bool {
+    let denominator = *time.denom();
+    let coordinate = position as i128 * denominator + velocity as i128 * time.numer();
+    min as i128 * denominator <= coordinate && coordinate <= max as i128 * denominator
+}
+
+fn count_hailstone_path_crossings_within_area(hail_stones: &[HailStone], test_area: &Area) -> i32 {
+    let mut count = 0;
+    for hail_stone_pair in hail_stones.iter().combinations(2) {
+        let Some(times) = calculate_hailstones_intersection_times(hail_stone_pair[0], hail_stone_pair[1]) else {
+            continue;
+        };
+        // both hailstones still have to get there
+        if !times.iter().all(|time| time.is_positive()) {
+            continue;
+        }
+        let hail_stone = hail_stone_pair[0];
+        if is_within(hail_stone.position.x, hail_stone.velocity.x, &times[0], test_area.min_x, test_area.max_x)
+            && is_within(hail_stone.position.y, hail_stone.velocity.y, &times[0], test_area.min_y, test_area.max_y) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn parse_hailstorm(input: &str, test_area: Area) -> Hailstorm {
+    let hail_stones = parse::lines(input, get_hailstone_from_line).unwrap_or_else(|err| panic!("{}", err));
+    Hailstorm { hail_stones, test_area }
+}
+
+fn part_one(hailstorm: &Hailstorm) -> i32 {
+    count_hailstone_path_crossings_within_area(&hailstorm.hail_stones, &hailstorm.test_area)
+}
+
+fn part_two(_hailstorm: &Hailstorm) -> i32 {
+    0
+}
+
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+
+    type Input<'a> = Hailstorm;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("2");
+
+    fn parse(input: &str) -> Hailstorm {
+        parse_hailstorm(input, Area::new(200000000000000, 200000000000000, 400000000000000, 400000000000000))
+    }
+
+    fn parse_example(input: &str) -> Hailstorm {
+        parse_hailstorm(input, Area::new(7, 7, 27, 27))
+    }
+
+    fn part_one(hailstorm: &Hailstorm) -> i32 {
+        part_one(hailstorm)
+    }
+
+    fn part_two(hailstorm: &Hailstorm) -> i32 {
+        part_two(hailstorm)
+    }
+}
@@ -1,7 +1,7 @@
 use itertools::Itertools;
-use advent_of_code_2024::utils;
+use crate::solution::Solution;
 
-fn part_one(input: &String) -> i32 {
+fn part_one(input: &str) -> i32 {
     let mut depth = 0;
     let mut horizontal_distance = 0;
     for line in input.lines() {
@@ -14,18 +14,17 @@ fn part_one(input: &String) -> i32 {
             _ => panic!("aahh!"),
         };
     }
-    println!("Horizontal: {horizontal_distance}, Depth: {depth}");
-    return horizontal_distance * depth;
+    horizontal_distance * depth
 }
 
-fn part_two(input: &String) -> i32 {
+fn part_two(input: &str) -> i32 {
     let mut depth = 0;
     let mut horizontal_distance = 0;
     let mut aim = 0;
     for line in input.lines() {
         let (direction, distance) = line.split(' ').collect_tuple().unwrap();
         let distance = distance.parse::<i32>().unwrap();
-        let _ = match direction {
+        match direction {
             "forward" => {
                 horizontal_distance += distance;
                 depth += aim * distance;
@@ -35,14 +34,32 @@ fn part_two(input: &String) -> i32 {
             _ => panic!("aahh!"),
         };
     }
-    println!("Horizontal: {horizontal_distance}, Depth: {depth}");
-    return horizontal_distance * depth;
+    horizontal_distance * depth
 }
 
-fn main() {
-    // let input = utils::read_input_from_path("example_input/day2_2021.txt");
-    let input = utils::read_input_from_path("input/day2_2021.txt");
 
-    println!("Part One: {}", part_one(&input));
-    println!("Part Two: {}", part_two(&input));
-}
\ No newline at end of file
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("150");
+    const EXAMPLE_TWO: Option<&'static str> = Some("900");
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::solution::Solution;
+use crate::utils::bit;
+use crate::utils::bit::BitVec;
+
+fn collect_bytes(input: &str) -> Vec<BitVec> {
+    input.lines()
+        .map(|line| BitVec::from_binary(line).unwrap_or_else(|err| panic!("{}", err)))
+        .collect()
+}
+
+fn to_decimal(bits: &BitVec) -> i32 {
+    bits.to_int().unwrap_or_else(|| panic!("{} does not fit an i32", bits))
+}
+
+fn part_one(bytes: &[BitVec]) -> i32 {
+    let gamma_bits = bit::majority(bytes, true);
+    let elipson_bits = !gamma_bits.clone();
+
+    let gamma_rate = to_decimal(&gamma_bits);
+    let elipson_rate = to_decimal(&elipson_bits);
+    gamma_rate * elipson_rate
+}
+
+/// Keeps the bytes whose bit matches `criteria` on the remaining bytes, one column at a time, until one byte is left
+fn find_rating(bytes: &[BitVec], criteria: fn(&[BitVec], usize) -> bool) -> i32 {
+    let mut rating_bytes = bytes.to_vec();
+    for i in 0..bytes[0].len() {
+        if rating_bytes.len() == 1 {
+            break;
+        }
+        let wanted_bit = criteria(&rating_bytes, i);
+        rating_bytes.retain(|byte| byte.get(i) == wanted_bit);
+    }
+    to_decimal(&rating_bytes[0])
+}
+
+fn part_two(bytes: &[BitVec]) -> i32 {
+    let oxygen_generator_rating = find_rating(bytes, |bytes, i| bit::most_common(bytes, i, true));
+    let co2_scrubber_rating = find_rating(bytes, |bytes, i| bit::least_common(bytes, i, false));
+    oxygen_generator_rating * co2_scrubber_rating
+}
+
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<BitVec>;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("198");
+    const EXAMPLE_TWO: Option<&'static str> = Some("230");
+
+    fn parse(input: &str) -> Vec<BitVec> {
+        collect_bytes(input)
+    }
+
+    fn part_one(bytes: &Vec<BitVec>) -> i32 {
+        part_one(bytes)
+    }
+
+    fn part_two(bytes: &Vec<BitVec>) -> i32 {
+        part_two(bytes)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::utils::parse::{ParseError, ParseErrorKind};
+
+const BINGO_CARD_SIZE: usize = 5;
+
+fn collect_bingo_card_row(line: &str) -> Result<Vec<i32>, ParseError> {
+    let row = parse::separated::<i32>(line, ' ')?;
+    match row.len() == BINGO_CARD_SIZE {
+        true => Ok(row),
+        false => Err(ParseError { line: 1, column: 1, kind: ParseErrorKind::Count { what: "numbers", expected: BINGO_CARD_SIZE, found: row.len() } }),
+    }
+}
+
+/// The drawn numbers and the bingo cards
+pub struct Bingo {
+    numbers: Vec<i32>,
+    cards: Vec<Grid<i32>>
+}
+
+fn collect_bingo(input: &str) -> Result<Bingo, ParseError> {
+    let blocks = parse::blocks(input);
+    let Some((drawn, cards)) = blocks.split_first() else {
+        return Ok(Bingo { numbers: Vec::new(), cards: Vec::new() });
+    };
+    drawn.expect_lines(1)?;
+    let bingo_numbers = drawn.parse_lines(|line| parse::separated::<i32>(line, ','))?.concat();
+
+    let mut bingo_cards: Vec<Grid<i32>> = Vec::new();
+    for card in cards {
+        card.expect_lines(BINGO_CARD_SIZE)?;
+        let rows = card.parse_lines(collect_bingo_card_row)?;
+        bingo_cards.push(Grid::from_vec(BINGO_CARD_SIZE, BINGO_CARD_SIZE, rows.concat()).expect("every row has the size of the card"));
+    }
+    Ok(Bingo { numbers: bingo_numbers, cards: bingo_cards })
+}
+
+/// The scores of the cards in the order they win, the sum of their unmarked numbers times the number that completed them
+fn winning_scores(bingo_numbers: &[i32], bingo_cards: &[Grid<i32>]) -> Vec<i32> {
+    let mut marked: Vec<Grid<bool>> = bingo_cards.iter().map(|bingo_card| bingo_card.map(|_| false)).collect();
+    let mut won = vec![false; bingo_cards.len()];
+    let mut scores = Vec::new();
+    for &number in bingo_numbers {
+        for (index, bingo_card) in bingo_cards.iter().enumerate() {
+            if won[index] {
+                continue;
+            }
+            let Some((row, col)) = bingo_card.find(|&value| value == number) else {
+                continue;
+            };
+            let marks = &mut marked[index];
+            marks[(row, col)] = true;
+            if marks.row(row).iter().all(|&mark| mark) || marks.column(col).all(|&mark| mark) {
+                won[index] = true;
+                let unmarked: i32 = bingo_card.iter().filter(|&(position, _)| !marks[position]).map(|(_, value)| value).sum();
+                scores.push(unmarked * number);
+            }
+        }
+    }
+    scores
+}
+
+fn part_one(bingo: &Bingo) -> i32 {
+    *winning_scores(&bingo.numbers, &bingo.cards).first().expect("a card wins")
+}
+
+fn part_two(bingo: &Bingo) -> i32 {
+    *winning_scores(&bingo.numbers, &bingo.cards).last().expect("a card wins")
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Bingo;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = Some("4512");
+    const EXAMPLE_TWO: Option<&'static str> = Some("1924");
+
+    fn parse(input: &str) -> Bingo {
+        collect_bingo(input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part_one(bingo: &Bingo) -> i32 {
+        part_one(bingo)
+    }
+
+    fn part_two(bingo: &Bingo) -> i32 {
+        part_two(bingo)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::solution::Solution;
+
+// `new` generates every day from this file, see scaffold.rs
+
+fn part_one(_input: &str) -> i32 {
+    todo!()
+}
+
+fn part_two(_input: &str) -> i32 {
+    todo!()
+}
+
+
+pub struct Template;
+
+impl Solution for Template {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 0;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    const EXAMPLE_ONE: Option<&'static str> = None;
+    const EXAMPLE_TWO: Option<&'static str> = None;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> i32 {
+        part_two(input)
+    }
+}
@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+use num::PrimInt;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Bit `position` of an integer, counted from the least significant bit
+pub fn get_bit<T: PrimInt>(value: T, position: usize) -> bool {
+    (value >> position) & T::one() == T::one()
+}
+
+pub fn set_bit<T: PrimInt>(value: T, position: usize, bit: bool) -> T {
+    match bit {
+        true => value | (T::one() << position),
+        false => value & !(T::one() << position),
+    }
+}
+
+pub fn flip_bit<T: PrimInt>(value: T, position: usize) -> T {
+    value ^ (T::one() << position)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BitError {
+    InvalidDigit { index: usize, found: char }
+}
+
+impl Display for BitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitError::InvalidDigit { index, found } => write!(f, "expected 0 or 1 at index {}, found '{}'", index, found),
+        }
+    }
+}
+
+impl std::error::Error for BitError {}
+
+/// A fixed number of bits, indexed like a binary string: bit 0 is the leftmost, most significant one
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BitVec {
+    len: usize,
+    /// Bit `i` is bit `i % 64` of word `i / 64`, unused bits of the last word stay 0
+    words: Vec<u64>
+}
+
+impl BitVec {
+    /// `len` bits, all 0
+    pub fn new(len: usize) -> BitVec {
+        BitVec { len, words: vec![0; len.div_ceil(WORD_BITS)] }
+    }
+
+    pub fn from_binary(text: &str) -> Result<BitVec, BitError> {
+        let mut bits = BitVec::new(text.chars().count());
+        for (index, char) in text.chars().enumerate() {
+            match char {
+                '0' => {}
+                '1' => bits.set(index, true),
+                found => return Err(BitError::InvalidDigit { index, found }),
+            }
+        }
+        Ok(bits)
+    }
+
+    /// The lowest `width` bits of `value`, most significant first
+    pub fn from_int<T: PrimInt>(value: T, width: usize) -> BitVec {
+        (0..width).rev().map(|position| position < size_of::<T>() * 8 && get_bit(value, position)).collect()
+    }
+
+    /// `None` if a set bit does not fit in `T`
+    pub fn to_int<T: PrimInt>(&self) -> Option<T> {
+        self.iter().try_fold(T::zero(), |value, bit| {
+            let shifted = value.checked_mul(&(T::one() + T::one()))?;
+            Some(match bit {
+                true => shifted + T::one(),
+                false => shifted,
+            })
+        })
+    }
+
+    pub fn to_binary(&self) -> String {
+        self.iter().map(|bit| if bit { '1' } else { '0' }).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(index < self.len, "bit {} out of range for {} bits", index, self.len);
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, index: usize, bit: bool) {
+        let (word, mask) = self.locate(index);
+        match bit {
+            true => self.words[word] |= mask,
+            false => self.words[word] &= !mask,
+        }
+    }
+
+    pub fn flip(&mut self, index: usize) {
+        let (word, mask) = self.locate(index);
+        self.words[word] ^= mask;
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|index| self.get(index))
+    }
+
+    /// Indices of the set bits, in increasing order
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(word_index * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn zip_words(mut self, other: &BitVec, combine: impl Fn(u64, u64) -> u64) -> BitVec {
+        assert_eq!(self.len, other.len, "bit vectors differ in length");
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = combine(*word, other);
+        }
+        self
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitVec::new(0);
+        for bit in iter {
+            if bits.len.is_multiple_of(WORD_BITS) {
+                bits.words.push(0);
+            }
+            bits.len += 1;
+            bits.set(bits.len - 1, bit);
+        }
+        bits
+    }
+}
+
+impl Not for BitVec {
+    type Output = BitVec;
+
+    fn not(mut self) -> BitVec {
+        for word in self.words.iter_mut() {
+            *word = !*word;
+        }
+        if let Some(last) = self.words.last_mut() {
+            let used = self.len % WORD_BITS;
+            if used != 0 {
+                *last &= (1 << used) - 1;
+            }
+        }
+        self
+    }
+}
+
+impl BitAnd<&BitVec> for BitVec {
+    type Output = BitVec;
+
+    fn bitand(self, other: &BitVec) -> BitVec {
+        self.zip_words(other, |a, b| a & b)
+    }
+}
+
+impl BitOr<&BitVec> for BitVec {
+    type Output = BitVec;
+
+    fn bitor(self, other: &BitVec) -> BitVec {
+        self.zip_words(other, |a, b| a | b)
+    }
+}
+
+impl BitXor<&BitVec> for BitVec {
+    type Output = BitVec;
+
+    fn bitxor(self, other: &BitVec) -> BitVec {
+        self.zip_words(other, |a, b| a ^ b)
+    }
+}
+
+impl Display for BitVec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_binary())
+    }
+}
+
+/// Number of rows with bit `column` set
+pub fn column_ones(rows: &[BitVec], column: usize) -> usize {
+    rows.iter().filter(|row| row.get(column)).count()
+}
+
+/// The bit most rows have in `column`, `tie` if there are as many ones as zeros
+pub fn most_common(rows: &[BitVec], column: usize, tie: bool) -> bool {
+    let ones = column_ones(rows, column);
+    match (ones * 2).cmp(&rows.len()) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        Ordering::Equal => tie,
+    }
+}
+
+/// The bit fewest rows have in `column`, `tie` if there are as many ones as zeros
+pub fn least_common(rows: &[BitVec], column: usize, tie: bool) -> bool {
+    let ones = column_ones(rows, column);
+    match (ones * 2).cmp(&rows.len()) {
+        Ordering::Greater => false,
+        Ordering::Less => true,
+        Ordering::Equal => tie,
+    }
+}
+
+/// The most common bit of every column, all rows need the same length
+pub fn majority(rows: &[BitVec], tie: bool) -> BitVec {
+    let width = rows.first().map_or(0, BitVec::len);
+    (0..width).map(|column| most_common(rows, column, tie)).collect()
+}
+
+/// The least common bit of every column, all rows need the same length
+pub fn minority(rows: &[BitVec], tie: bool) -> BitVec {
+    let width = rows.first().map_or(0, BitVec::len);
+    (0..width).map(|column| least_common(rows, column, tie)).collect()
+}
@@ -0,0 +1,139 @@
+use crate::utils::direction::Direction;
+use crate::utils::grid::{Grid, Position};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// One bit per cell of a `width` x `height` grid, or one per cell and orthogonal direction.
+/// Meant as the visited set of grid walks and flood fills, positions outside the grid panic.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// 1, or 4 when every orthogonal direction gets its own bit
+    planes: usize,
+    words: Vec<u64>
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        BitGrid::with_planes(width, height, 1)
+    }
+
+    /// A bit per cell and orthogonal direction, to tell whether a walk was at a position facing the same way before
+    pub fn with_directions(width: usize, height: usize) -> BitGrid {
+        BitGrid::with_planes(width, height, 4)
+    }
+
+    /// A visited set the size of `grid`
+    pub fn for_grid<T>(grid: &Grid<T>) -> BitGrid {
+        BitGrid::new(grid.width(), grid.height())
+    }
+
+    fn with_planes(width: usize, height: usize, planes: usize) -> BitGrid {
+        BitGrid { width, height, planes, words: vec![0; (width * height * planes).div_ceil(WORD_BITS)] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn has_directions(&self) -> bool {
+        self.planes == 4
+    }
+
+    fn locate(&self, (row, col): Position, plane: usize) -> (usize, u64) {
+        assert!(row < self.height && col < self.width, "({}, {}) is outside the {}x{} grid", row, col, self.width, self.height);
+        let index = (row * self.width + col) * self.planes + plane;
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    fn plane(&self, direction: Direction) -> usize {
+        assert!(self.has_directions(), "this bit grid has no direction planes");
+        match direction {
+            Direction::North => 0,
+            Direction::East => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+            _ => panic!("bit grids only store orthogonal directions, not {:?}", direction),
+        }
+    }
+
+    fn insert_bit(&mut self, position: Position, plane: usize) -> bool {
+        let (word, mask) = self.locate(position, plane);
+        let new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        new
+    }
+
+    /// `true` if the position was not in the set yet
+    pub fn insert(&mut self, position: Position) -> bool {
+        assert!(!self.has_directions(), "use insert_directed on a bit grid with directions");
+        self.insert_bit(position, 0)
+    }
+
+    /// With direction planes, whether the position is in the set in any direction
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.planes).any(|plane| {
+            let (word, mask) = self.locate(position, plane);
+            self.words[word] & mask != 0
+        })
+    }
+
+    pub fn remove(&mut self, position: Position) {
+        for plane in 0..self.planes {
+            let (word, mask) = self.locate(position, plane);
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// `true` if the position was not in the set facing `direction` yet
+    pub fn insert_directed(&mut self, position: Position, direction: Direction) -> bool {
+        let plane = self.plane(direction);
+        self.insert_bit(position, plane)
+    }
+
+    pub fn contains_directed(&self, position: Position, direction: Direction) -> bool {
+        let (word, mask) = self.locate(position, self.plane(direction));
+        self.words[word] & mask != 0
+    }
+
+    /// Adds everything in `other`, which needs the same size and planes
+    pub fn union(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height, self.planes), (other.width, other.height, other.planes), "bit grids differ in shape");
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Number of positions in the set, in any direction
+    pub fn count(&self) -> usize {
+        match self.planes {
+            1 => self.words.iter().map(|word| word.count_ones() as usize).sum(),
+            _ => self.positions().count(),
+        }
+    }
+
+    /// Number of (position, direction) pairs in the set
+    pub fn count_directed(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The positions in the set row by row, once each
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height)
+            .flat_map(move |row| (0..self.width).map(move |col| (row, col)))
+            .filter(|&position| self.contains(position))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+}
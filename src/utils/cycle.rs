@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states after `start` steps repeat every `length` steps
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize
+}
+
+impl Cycle {
+    /// The smallest number of steps that ends in the same state as `steps` steps
+    pub fn reduce(&self, steps: usize) -> usize {
+        match steps < self.start {
+            true => steps,
+            false => self.start + (steps - self.start) % self.length,
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, constant memory. Never returns if the states do not cycle.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+
+    // the hare is a multiple of the cycle length ahead, so both meet where the cycle starts
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, constant memory and fewer steps than `floyd`. Never returns if the states do not cycle.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Remembers every state, so each one is computed once. `None` if `next` ends the walk before a state repeats.
+pub fn try_find_cycle<S: Clone + Eq + Hash>(initial: S, mut next: impl FnMut(&S) -> Option<S>) -> Option<Cycle> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+    for step in 0.. {
+        if let Some(&start) = seen.get(&state) {
+            return Some(Cycle { start, length: step - start });
+        }
+        let following = next(&state)?;
+        seen.insert(state, step);
+        state = following;
+    }
+    unreachable!("the loop only ends by returning")
+}
+
+/// Hash based cycle detection for walks that go on forever
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    try_find_cycle(initial, |state| Some(next(state))).expect("next never ends the walk")
+}
+
+/// The state after `steps` steps, skipping whole cycles. Never returns if the states do not cycle.
+pub fn fast_forward<S: Clone + PartialEq>(initial: S, mut next: impl FnMut(&S) -> S, steps: usize) -> S {
+    let cycle = brent(initial.clone(), &mut next);
+    (0..cycle.reduce(steps)).fold(initial, |state, _| next(&state))
+}
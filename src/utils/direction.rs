@@ -0,0 +1,76 @@
+use crate::utils::point::Point2D;
+
+/// Compass directions on a grid, north is up (towards row 0)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl Direction {
+    /// Clockwise starting north
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest
+    ];
+    /// Clockwise starting north
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+    /// Clockwise starting north-east
+    pub const DIAGONAL: [Direction; 4] = [Direction::NorthEast, Direction::SouthEast, Direction::SouthWest, Direction::NorthWest];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns clockwise in steps of 45°
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self.index() + eighths) % 8]
+    }
+
+    /// Turns 90° to the right, diagonals stay diagonal
+    pub fn rotate_clockwise(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// Turns 90° to the left, diagonals stay diagonal
+    pub fn rotate_counter_clockwise(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn rotate_clockwise_45(self) -> Direction {
+        self.turn(1)
+    }
+
+    pub fn rotate_counter_clockwise_45(self) -> Direction {
+        self.turn(7)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// One step in this direction, `x` to the east and `y` to the south
+    pub fn delta<T: From<i8>>(self) -> Point2D<T> {
+        let (x, y): (i8, i8) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Point2D::new(T::from(x), T::from(y))
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+pub type NodeId = usize;
+
+/// A graph over named nodes, names are interned once and nodes are referred to by `NodeId` after that
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    directed: bool,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// Outgoing edges, in an undirected graph every edge is stored in both directions
+    adjacency: Vec<BTreeSet<NodeId>>,
+    /// Incoming edges, only kept for directed graphs
+    incoming: Vec<BTreeSet<NodeId>>
+}
+
+impl Graph {
+    pub fn undirected() -> Graph {
+        Graph::default()
+    }
+
+    pub fn directed() -> Graph {
+        Graph { directed: true, ..Graph::default() }
+    }
+
+    pub fn undirected_from_edges<'s>(edges: impl IntoIterator<Item = (&'s str, &'s str)>) -> Graph {
+        let mut graph = Graph::undirected();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    pub fn directed_from_edges<'s>(edges: impl IntoIterator<Item = (&'s str, &'s str)>) -> Graph {
+        let mut graph = Graph::directed();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The id of `name`, adding the node if it is new
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adjacency.push(BTreeSet::new());
+        self.incoming.push(BTreeSet::new());
+        id
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.add_edge_between(from, to);
+    }
+
+    pub fn add_edge_between(&mut self, from: NodeId, to: NodeId) {
+        self.adjacency[from].insert(to);
+        match self.directed {
+            true => self.incoming[to].insert(from),
+            false => self.adjacency[to].insert(from),
+        };
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len()
+    }
+
+    /// Nodes reachable over one edge, in a directed graph only along its direction
+    pub fn neighbours(&self, id: NodeId) -> &BTreeSet<NodeId> {
+        &self.adjacency[id]
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.adjacency[from].contains(&to)
+    }
+
+    pub fn edge_count(&self) -> usize {
+        let stored: usize = self.adjacency.iter().map(BTreeSet::len).sum();
+        match self.directed {
+            true => stored,
+            false => stored / 2,
+        }
+    }
+
+    /// Neighbours ignoring the direction of edges and self loops
+    fn links(&self) -> Vec<BTreeSet<NodeId>> {
+        let mut links: Vec<BTreeSet<NodeId>> = match self.directed {
+            true => self.adjacency.iter().zip(&self.incoming).map(|(outgoing, incoming)| outgoing | incoming).collect(),
+            false => self.adjacency.clone(),
+        };
+        for (id, linked) in links.iter_mut().enumerate() {
+            linked.remove(&id);
+        }
+        links
+    }
+
+    /// Nodes in the order a breadth first search from `start` reaches them
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &next in &self.adjacency[node] {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    /// Nodes in the order a depth first search from `start` reaches them, neighbours in id order
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+            stack.extend(self.adjacency[node].iter().rev().filter(|&&next| !visited[next]));
+        }
+        order
+    }
+
+    /// Groups of nodes connected by edges in either direction, each sorted by id
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let links = self.links();
+        let mut component_of = vec![None; self.len()];
+        let mut components = Vec::new();
+        for start in self.nodes() {
+            if component_of[start].is_some() {
+                continue;
+            }
+            let mut component = vec![start];
+            component_of[start] = Some(components.len());
+            let mut i = 0;
+            while i < component.len() {
+                for &next in &links[component[i]] {
+                    if component_of[next].is_none() {
+                        component_of[next] = Some(components.len());
+                        component.push(next);
+                    }
+                }
+                i += 1;
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Every set of three nodes that are all linked to each other, once, with ascending ids
+    pub fn triangles(&self) -> Vec<[NodeId; 3]> {
+        let links = self.links();
+        let mut triangles = Vec::new();
+        for a in self.nodes() {
+            for &b in links[a].range(a + 1..) {
+                for &c in links[b].range(b + 1..) {
+                    if links[a].contains(&c) {
+                        triangles.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Every clique that cannot be extended by another node, found with Bron–Kerbosch with pivoting
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeId>> {
+        let links = self.links();
+        let mut cliques = Vec::new();
+        bron_kerbosch(&links, Vec::new(), self.nodes().collect(), BTreeSet::new(), &mut cliques);
+        cliques
+    }
+
+    /// A largest clique, sorted by id
+    pub fn maximum_clique(&self) -> Vec<NodeId> {
+        self.maximal_cliques().into_iter().max_by_key(Vec::len).unwrap_or_default()
+    }
+}
+
+fn bron_kerbosch(links: &[BTreeSet<NodeId>],
+                 clique: Vec<NodeId>,
+                 mut candidates: BTreeSet<NodeId>,
+                 mut excluded: BTreeSet<NodeId>,
+                 cliques: &mut Vec<Vec<NodeId>>) {
+    if candidates.is_empty() && excluded.is_empty() {
+        let mut clique = clique;
+        clique.sort_unstable();
+        cliques.push(clique);
+        return;
+    }
+    // nodes linked to the pivot are found through the pivot or one of its other neighbours
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|&&node| links[node].intersection(&candidates).count())
+        .copied()
+        .expect("candidates or excluded is not empty");
+    let without_pivot_links: Vec<NodeId> = candidates.difference(&links[pivot]).copied().collect();
+    for node in without_pivot_links {
+        let mut extended = clique.clone();
+        extended.push(node);
+        bron_kerbosch(links,
+                      extended,
+                      &candidates & &links[node],
+                      &excluded & &links[node],
+                      cliques);
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
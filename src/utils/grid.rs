@@ -0,0 +1,252 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::utils::direction::Direction;
+use crate::utils::point::Point2D;
+
+/// Positions are `(row, col)`, row 0 is the first line of the input
+pub type Position = (usize, usize);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GridError {
+    Empty,
+    /// A line (1-based) is not as wide as the first one
+    Ragged { line: usize, expected: usize, found: usize },
+    /// `from_vec` got a number of cells that is not width * height
+    Size { width: usize, height: usize, cells: usize }
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid has no cells"),
+            GridError::Ragged { line, expected, found } => {
+                write!(f, "line {} is {} wide, expected {} like the first line", line, found, expected)
+            }
+            GridError::Size { width, height, cells } => write!(f, "{} cells do not fill a {}x{} grid", cells, width, height),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular grid stored row by row in a single `Vec`
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Grid<char>, GridError> {
+        Grid::parse(input, |char| char)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>, GridError> {
+        if cells.len() != width * height {
+            return Err(GridError::Size { width, height, cells: cells.len() });
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /// One row per line, every char mapped to a cell. Trailing blank lines are ignored.
+    pub fn parse(input: &str, mut map: impl FnMut(char) -> T) -> Result<Grid<T>, GridError> {
+        let lines: Vec<&str> = input.trim_end().lines().map(|line| line.trim_end_matches('\r')).collect();
+        let width = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut map));
+            if cells.len() - before != width {
+                return Err(GridError::Ragged { line: i + 1, expected: width, found: cells.len() - before });
+            }
+        }
+        Ok(Grid { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(|index| &mut self.cells[index])
+    }
+
+    /// Like `get`, for positions computed with signed steps that may have left the grid
+    pub fn get_signed(&self, row: i64, col: i64) -> Option<&T> {
+        self.checked_position(row, col).and_then(|(row, col)| self.get(row, col))
+    }
+
+    pub fn checked_position(&self, row: i64, col: i64) -> Option<Position> {
+        let (row, col) = (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        match row < self.height && col < self.width {
+            true => Some((row, col)),
+            false => None,
+        }
+    }
+
+    /// Points have `x` as column and `y` as row
+    pub fn get_point(&self, point: Point2D<i64>) -> Option<&T> {
+        self.get_signed(point.y, point.x)
+    }
+
+    pub fn point_position(&self, point: Point2D<i64>) -> Option<Position> {
+        self.checked_position(point.y, point.x)
+    }
+
+    /// The position one step away, if it is still inside the grid
+    pub fn step(&self, (row, col): Position, direction: Direction) -> Option<Position> {
+        let delta = direction.delta::<i64>();
+        self.checked_position(row as i64 + delta.y, col as i64 + delta.x)
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        match row < self.height && col < self.width {
+            true => Some(row * self.width + col),
+            false => None,
+        }
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        (index / self.width, index % self.width)
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.iter().enumerate().map(|(index, cell)| (self.position_of(index), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Position of the first cell, row by row, for which `predicate` holds
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.cells.iter().position(predicate).map(|index| self.position_of(index))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Cells from `start` in `direction` until the edge of the grid, starting with `start` itself
+    pub fn ray(&self, start: Position, direction: Direction) -> impl Iterator<Item = (Position, &T)> {
+        let first = self.index_of(start.0, start.1).map(|_| start);
+        std::iter::successors(first, move |&position| self.step(position, direction))
+            .map(|position| (position, &self[position]))
+    }
+
+    /// The diagonals running down-right, starting with the one in the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width, self.height);
+        (0..(width + height).saturating_sub(1)).map(move |i| {
+            let start = match i < height {
+                true => (height - 1 - i, 0),
+                false => (0, i + 1 - height),
+            };
+            self.ray(start, Direction::SouthEast).map(|(_, cell)| cell)
+        })
+    }
+
+    /// The diagonals running down-left, starting with the one in the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width, self.height);
+        (0..(width + height).saturating_sub(1)).map(move |i| {
+            let start = match i < width {
+                true => (0, i),
+                false => (i + 1 - width, width - 1),
+            };
+            self.ray(start, Direction::SouthWest).map(|(_, cell)| cell)
+        })
+    }
+
+    fn neighbours(&self, position: Position, directions: &'static [Direction]) -> impl Iterator<Item = Position> + '_ {
+        directions.iter().filter_map(move |&direction| self.step(position, direction))
+    }
+
+    /// Orthogonal neighbours inside the grid: north, east, south, west
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ORTHOGONAL)
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, clockwise starting north
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ALL)
+    }
+
+    /// The grid turned a quarter clockwise, the first row becomes the last column
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where T: Clone {
+        let cells = (0..self.width)
+            .flat_map(|row| (0..self.height).map(move |col| (self.height - 1 - col, row)))
+            .map(|position| self[position].clone())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    pub fn map<U>(&self, map: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(map).collect() }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", row, col, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", row, col, width, height))
+    }
+}
+
+/// One line per row, cells printed next to each other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
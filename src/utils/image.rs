@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use crate::utils::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// An RGB image stored row by row, written as binary PPM which most image viewers open
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Image {
+        Image { width, height, pixels: vec![fill; width * height] }
+    }
+
+    /// Every cell becomes a `scale` x `scale` block of pixels
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, color: impl Fn(&T) -> Rgb) -> Image {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, [0; 3]);
+        for ((row, col), cell) in grid.iter() {
+            let rgb = color(cell);
+            for y in row * scale..(row + 1) * scale {
+                image.pixels[y * image.width + col * scale..y * image.width + (col + 1) * scale].fill(rgb);
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `x` is the column and `y` the row, like points on a grid
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Rgb> {
+        match x < self.width && y < self.height {
+            true => Some(self.pixels[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        writer.write_all(&bytes)?;
+        writer.flush()
+    }
+
+    pub fn save_ppm(&self, path: &Path) -> io::Result<()> {
+        self.write_ppm(BufWriter::new(File::create(path)?))
+    }
+}
@@ -0,0 +1,113 @@
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::solution::Part;
+
+/// Overrides the `input` directory, e.g. to keep puzzle inputs outside the repository
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+const EXAMPLE_INPUT_DIR: &str = "example_input";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the candidate paths exist
+    Missing(Vec<PathBuf>),
+    NotUtf8(PathBuf),
+    Empty(PathBuf),
+    Io(PathBuf, io::Error)
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(candidates) => {
+                let tried: Vec<String> = candidates.iter().map(|path| path.display().to_string()).collect();
+                write!(f, "input file not found, tried {}", tried.join(", "))
+            }
+            InputError::NotUtf8(path) => write!(f, "{} is not valid UTF-8", path.display()),
+            InputError::Empty(path) => write!(f, "{} is empty", path.display()),
+            InputError::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn input_dir() -> PathBuf {
+    input_dir_from(std::env::var_os(INPUT_DIR_VAR))
+}
+
+/// The input directory for a value of `AOC_INPUT_DIR`, `input` when it is unset
+pub fn input_dir_from(value: Option<OsString>) -> PathBuf {
+    value
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// File name without extension in the flat layout, `day06` for 2024 and `day24_2023` for other years
+pub fn file_stem(year: u16, day: u8) -> String {
+    match year {
+        2024 => format!("day{:02}", day),
+        _ => format!("day{}_{}", day, year),
+    }
+}
+
+/// Both layouts that are in use: `<dir>/2023/day24.txt` and `<dir>/day24_2023.txt`
+fn candidates(dir: &Path, year: u16, day: u8, suffix: &str) -> Vec<PathBuf> {
+    vec![
+        dir.join(year.to_string()).join(format!("day{:02}{}.txt", day, suffix)),
+        dir.join(format!("{}{}.txt", file_stem(year, day), suffix)),
+    ]
+}
+
+fn first_existing(candidates: Vec<PathBuf>) -> Result<PathBuf, InputError> {
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(InputError::Missing(candidates)),
+    }
+}
+
+pub fn input_path(year: u16, day: u8) -> Result<PathBuf, InputError> {
+    input_path_in(&input_dir(), year, day)
+}
+
+pub fn input_path_in(dir: &Path, year: u16, day: u8) -> Result<PathBuf, InputError> {
+    first_existing(candidates(dir, year, day, ""))
+}
+
+/// Example inputs are shared by both parts, unless a day has a `_v1`/`_v2` file per part
+pub fn example_path(year: u16, day: u8, part: Part) -> Result<PathBuf, InputError> {
+    let dir = Path::new(EXAMPLE_INPUT_DIR);
+    let mut paths = candidates(dir, year, day, "");
+    paths.extend(candidates(dir, year, day, &format!("_v{}", part)));
+    first_existing(paths)
+}
+
+pub fn read_input(year: u16, day: u8) -> Result<String, InputError> {
+    read_input_in(&input_dir(), year, day)
+}
+
+pub fn read_input_in(dir: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    read_input_from_path(input_path_in(dir, year, day)?)
+}
+
+pub fn read_example(year: u16, day: u8, part: Part) -> Result<String, InputError> {
+    read_input_from_path(example_path(year, day, part)?)
+}
+
+pub fn read_input_from_path(input_path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = input_path.as_ref().to_path_buf();
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Err(InputError::Missing(vec![path])),
+        Err(err) => return Err(InputError::Io(path, err)),
+    };
+    let input = String::from_utf8(bytes).map_err(|_| InputError::NotUtf8(path.clone()))?;
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(path));
+    }
+    Ok(input)
+}
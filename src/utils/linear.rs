@@ -0,0 +1,125 @@
+use num::bigint::BigInt;
+use num::rational::{BigRational, Ratio};
+use num::{CheckedMul, CheckedSub, Integer, One, Zero};
+
+/// What a system of linear equations allows for its unknowns, exact rationals by default
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Solutions<T = BigRational> {
+    /// The equations contradict each other
+    None,
+    Unique(Vec<T>),
+    /// A solution with the `free` unknowns set to 0, other values for them give the other solutions
+    Infinite { particular: Vec<T>, free: Vec<usize> }
+}
+
+impl<I: Integer + Clone> Solutions<Ratio<I>> {
+    /// The unique solution if every value in it is whole
+    pub fn integers(&self) -> Option<Vec<I>> {
+        match self {
+            Solutions::Unique(values) => values.iter().map(|value| value.is_integer().then(|| value.to_integer())).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Solves `matrix * x == rhs` exactly with Gaussian elimination, `matrix` holds one row per equation
+pub fn solve(matrix: Vec<Vec<BigRational>>, rhs: Vec<BigRational>) -> Solutions {
+    assert_eq!(matrix.len(), rhs.len(), "every equation needs a right hand side");
+    // scaling an equation by the lcm of its denominators leaves only integers
+    let rows = matrix
+        .into_iter()
+        .zip(rhs)
+        .map(|(mut row, value)| {
+            row.push(value);
+            let scale = row.iter().fold(BigInt::one(), |scale, value| scale.lcm(value.denom()));
+            row.iter().map(|value| value.numer() * (&scale / value.denom())).collect()
+        })
+        .collect();
+    eliminate(rows).expect("big integers do not overflow")
+}
+
+/// `solve` for integer coefficients
+pub fn solve_integers<T: Copy + Into<BigInt>>(matrix: &[Vec<T>], rhs: &[T]) -> Solutions {
+    eliminate(integer_rows(matrix, rhs)).expect("big integers do not overflow")
+}
+
+/// `solve_integers` without big integers, which is a lot faster for the small systems of the puzzles,
+/// e.g. two equations with coordinates up to 2^53. `None` if a value outgrows an i128 on the way.
+pub fn solve_i128<T: Copy + Into<i128>>(matrix: &[Vec<T>], rhs: &[T]) -> Option<Solutions<Ratio<i128>>> {
+    eliminate(integer_rows(matrix, rhs))
+}
+
+fn integer_rows<T: Copy + Into<I>, I>(matrix: &[Vec<T>], rhs: &[T]) -> Vec<Vec<I>> {
+    assert_eq!(matrix.len(), rhs.len(), "every equation needs a right hand side");
+    matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| row.iter().copied().chain([value]).map(Into::into).collect())
+        .collect()
+}
+
+/// Fraction free Gauss-Jordan elimination on rows of coefficients followed by the right hand side,
+/// rationals only appear when reading off the solution. `None` if a value overflows `I`.
+fn eliminate<I>(mut rows: Vec<Vec<I>>) -> Option<Solutions<Ratio<I>>>
+where I: Integer + Clone + CheckedMul + CheckedSub {
+    let unknowns = rows.first().map_or(0, |row| row.len() - 1);
+    assert!(rows.iter().all(|row| row.len() == unknowns + 1), "every equation needs the same number of unknowns");
+
+    // pivots[i] is the unknown that row i solves. Dividing by the previous pivot (Bareiss) is exact
+    // and keeps the values as small as the determinants of the matrix.
+    let mut pivots: Vec<usize> = Vec::new();
+    let mut previous = I::one();
+    for column in 0..unknowns {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&candidate| !rows[candidate][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+        let pivot_row = rows[row].clone();
+        let pivot = pivot_row[column].clone();
+        for (_, other_row) in rows.iter_mut().enumerate().filter(|&(other, _)| other != row) {
+            let factor = other_row[column].clone();
+            for (value, pivot_value) in other_row.iter_mut().zip(&pivot_row) {
+                let cross = value.checked_mul(&pivot)?.checked_sub(&pivot_value.checked_mul(&factor)?)?;
+                // only checked in debug builds, a division and a remainder of i128 cost as much as the rest together
+                debug_assert!((cross.clone() % previous.clone()).is_zero(), "Bareiss division must be exact");
+                *value = cross / previous.clone();
+            }
+        }
+        previous = pivot;
+        pivots.push(column);
+    }
+
+    // a row without unknowns left that still has a value reads 0 == value
+    if rows[pivots.len()..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Some(Solutions::None);
+    }
+    let mut particular = vec![Ratio::zero(); unknowns];
+    for (row, &column) in pivots.iter().enumerate() {
+        particular[column] = reduced(rows[row][unknowns].clone(), rows[row][column].clone());
+    }
+    match pivots.len() == unknowns {
+        true => Some(Solutions::Unique(particular)),
+        false => {
+            let free = (0..unknowns).filter(|column| !pivots.contains(column)).collect();
+            Some(Solutions::Infinite { particular, free })
+        }
+    }
+}
+
+/// `Ratio::new`, but with Euclid's gcd. The binary gcd of `num` takes a step per bit, while the
+/// numerators here are often far longer than the determinant they are divided by.
+fn reduced<I: Integer + Clone>(numerator: I, denominator: I) -> Ratio<I> {
+    let (mut a, mut b) = (numerator.clone(), denominator.clone());
+    while !b.is_zero() {
+        let remainder = a % b.clone();
+        a = b;
+        b = remainder;
+    }
+    // a is the gcd up to its sign, pick the sign that leaves a positive denominator
+    let gcd = match (denominator < I::zero()) == (a < I::zero()) {
+        true => a,
+        false => I::zero() - a,
+    };
+    Ratio::new_raw(numerator / gcd.clone(), denominator / gcd)
+}
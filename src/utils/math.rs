@@ -0,0 +1,112 @@
+use num::{Integer, Signed};
+
+/// Greatest common divisor, never negative
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// Least common multiple, never negative
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// Least common multiple of all `values`, 1 if there are none
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), |acc, value| acc.lcm(&value))
+}
+
+/// `(g, x, y)` with `a * x + b * y == g` and `g == gcd(a, b)`
+pub fn extended_gcd<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    match old_r.is_negative() {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// `value` modulo a positive `modulus`, in `0..modulus` like `rem_euclid`
+pub fn modulo<T: Integer + Copy>(value: T, modulus: T) -> T {
+    value.mod_floor(&modulus)
+}
+
+pub fn mod_add<T: Integer + Copy>(a: T, b: T, modulus: T) -> T {
+    modulo(modulo(a, modulus) + modulo(b, modulus), modulus)
+}
+
+pub fn mod_sub<T: Integer + Copy>(a: T, b: T, modulus: T) -> T {
+    modulo(modulo(a, modulus) - modulo(b, modulus), modulus)
+}
+
+/// Overflows when `modulus * modulus` does not fit `T`
+pub fn mod_mul<T: Integer + Copy>(a: T, b: T, modulus: T) -> T {
+    modulo(modulo(a, modulus) * modulo(b, modulus), modulus)
+}
+
+/// `base` to the power `exponent` modulo `modulus`, by squaring
+pub fn mod_pow<T: Integer + Copy>(base: T, mut exponent: u64, modulus: T) -> T {
+    let mut result = modulo(T::one(), modulus);
+    let mut base = modulo(base, modulus);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// `x` with `a * x` congruent to 1, `None` unless `a` and `modulus` are coprime
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus), modulus);
+    g.is_one().then(|| modulo(x, modulus))
+}
+
+/// Chinese remainder theorem over `(remainder, modulus)` pairs, the moduli need not be coprime.
+/// Returns `(x, m)` such that every solution is `x + k * m`, or `None` if the congruences contradict.
+pub fn crt<T: Integer + Signed + Copy>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold((T::zero(), T::one()), |(x, m), &(remainder, modulus)| {
+        let (g, p, _) = extended_gcd(m, modulus);
+        let difference = remainder - x;
+        if !(difference % g).is_zero() {
+            return None;
+        }
+        let step = modulus / g;
+        let combined = m * step;
+        let k = mod_mul(difference / g, p, step);
+        Some((modulo(x + m * k, combined), combined))
+    })
+}
+
+/// One solution `(x, y)` of `a * x + b * y == c`, all others are `(x + k * b / g, y - k * a / g)` with `g = gcd(a, b)`
+pub fn linear_diophantine<T: Integer + Signed + Copy>(a: T, b: T, c: T) -> Option<(T, T)> {
+    let (g, x, y) = extended_gcd(a, b);
+    if g.is_zero() {
+        return c.is_zero().then(|| (T::zero(), T::zero()));
+    }
+    if !(c % g).is_zero() {
+        return None;
+    }
+    let scale = c / g;
+    Some((x * scale, y * scale))
+}
+
+/// The integer `(x, y)` that solves both `a * x + b * y == e` and `c * x + d * y == f`.
+/// `None` if the solution is not whole or not unique (the determinant is 0).
+pub fn solve_2x2<T: Integer + Signed + Copy>([[a, b], [c, d]]: [[T; 2]; 2], [e, f]: [T; 2]) -> Option<(T, T)> {
+    let determinant = a * d - b * c;
+    if determinant.is_zero() {
+        return None;
+    }
+    let (x, x_rest) = (e * d - b * f).div_rem(&determinant);
+    let (y, y_rest) = (a * f - e * c).div_rem(&determinant);
+    (x_rest.is_zero() && y_rest.is_zero()).then_some((x, y))
+}
@@ -0,0 +1,152 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Cached values
+    pub entries: usize
+}
+
+impl MemoStats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// Percentage of lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64 * 100.0,
+        }
+    }
+}
+
+impl Add for MemoStats {
+    type Output = MemoStats;
+
+    fn add(self, other: MemoStats) -> MemoStats {
+        MemoStats { hits: self.hits + other.hits, misses: self.misses + other.misses, entries: self.entries + other.entries }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1}% of {}", self.hit_rate(), self.lookups())
+    }
+}
+
+thread_local! {
+    /// `None` outside of `recording`, else the stats of the memos dropped so far, if any
+    static RECORDED: Cell<Option<Option<MemoStats>>> = const { Cell::new(None) };
+}
+
+/// Puts back the recording `recording` replaced, also when `f` panics
+struct Restore(Option<Option<MemoStats>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        RECORDED.set(self.0);
+    }
+}
+
+/// Runs `f` and sums the stats of every `Memo` it dropped, `None` if no memo was used.
+/// Only memos dropped on the calling thread count, so tests and other threads can record at the same time.
+/// A nested recording counts towards the one around it as well.
+pub fn recording<R>(f: impl FnOnce() -> R) -> (R, Option<MemoStats>) {
+    let mut outer = Restore(RECORDED.replace(Some(None)));
+    let result = f();
+    let recorded = RECORDED.get().flatten();
+    if let (Some(outer), Some(stats)) = (&mut outer.0, recorded) {
+        *outer = Some(outer.unwrap_or_default() + stats);
+    }
+    (result, recorded)
+}
+
+/// A cache of computed values by key, that counts how often it saved the work
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo { cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    /// The cached value, counted as a hit or a miss
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.cache.get(key).cloned();
+        match value.is_some() {
+            true => self.hits += 1,
+            false => self.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute();
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// Memoizes a recursive function. `f` gets the key and a function to recurse through, which uses the cache too:
+    /// `memo.recurse(n, &|fib, n| if n < 2 { n } else { fib(n - 1) + fib(n - 2) })`
+    pub fn recurse<F>(&mut self, key: K, f: &F) -> V
+    where F: Fn(&mut dyn FnMut(K) -> V, K) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f(&mut |inner| self.recurse(inner, f), key.clone());
+        self.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn stats(&self) -> MemoStats {
+        MemoStats { hits: self.hits, misses: self.misses, entries: self.cache.len() }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        // `try_with` as a memo can outlive the thread local when it is dropped during thread teardown
+        let stats = self.stats();
+        let _ = RECORDED.try_with(|recorded| {
+            if let Some(sum) = recorded.get() {
+                recorded.set(Some(Some(sum.unwrap_or_default() + stats)));
+            }
+        });
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    InvalidNumber { token: String, reason: String },
+    MissingSeparator(char),
+    Count { what: &'static str, expected: usize, found: usize }
+}
+
+/// Where parsing failed, lines and columns are 1-based.
+/// Functions that parse a single line report line 1, `lines` and `Block::parse_lines` fill in the real line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind
+}
+
+impl ParseError {
+    fn new(column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line: 1, column, kind }
+    }
+
+    pub fn at_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::InvalidNumber { token, reason } => write!(f, "'{}' is not a valid number ({})", token, reason),
+            ParseErrorKind::MissingSeparator(separator) => write!(f, "expected '{}'", separator),
+            ParseErrorKind::Count { what, expected, found } => write!(f, "expected {} {}, found {}", expected, what, found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn number<T: FromStr>(token: &str, column: usize) -> Result<T, ParseError>
+where <T as FromStr>::Err: Display {
+    token.parse::<T>().map_err(|err| {
+        ParseError::new(column, ParseErrorKind::InvalidNumber { token: token.to_string(), reason: err.to_string() })
+    })
+}
+
+/// Every integer in a line of free text, like `p=0,4 v=3,-3`. A `-` is a sign when a digit follows and no digit precedes it.
+pub fn signed_ints<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError>
+where <T as FromStr>::Err: Display {
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let is_sign = chars[i].1 == '-'
+            && chars.get(i + 1).is_some_and(|(_, next)| next.is_ascii_digit())
+            && (i == 0 || !chars[i - 1].1.is_ascii_digit());
+        if !is_sign && !chars[i].1.is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < chars.len() && chars[i].1.is_ascii_digit() {
+            i += 1;
+        }
+        let end = chars.get(i).map(|(byte, _)| *byte).unwrap_or(line.len());
+        numbers.push(number(&line[chars[start].0..end], start + 1)?);
+    }
+    Ok(numbers)
+}
+
+/// `signed_ints` for lines that hold exactly `N` numbers
+pub fn signed_ints_exact<T: FromStr, const N: usize>(line: &str) -> Result<[T; N], ParseError>
+where <T as FromStr>::Err: Display {
+    let numbers = signed_ints::<T>(line)?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::new(1, ParseErrorKind::Count { what: "numbers", expected: N, found }))
+}
+
+/// Numbers between `separator`s. Empty tokens, as from repeated spaces, are skipped, anything else has to parse.
+pub fn separated<T: FromStr>(line: &str, separator: char) -> Result<Vec<T>, ParseError>
+where <T as FromStr>::Err: Display {
+    let mut numbers = Vec::new();
+    let mut column = 1;
+    for token in line.split(separator) {
+        let trimmed = token.trim_start();
+        let token_column = column + token[..token.len() - trimmed.len()].chars().count();
+        if !trimmed.trim_end().is_empty() {
+            numbers.push(number(trimmed.trim_end(), token_column)?);
+        }
+        column += token.chars().count() + 1;
+    }
+    Ok(numbers)
+}
+
+/// Splits `key<separator>value` at the first separator, both sides trimmed
+pub fn key_value(line: &str, separator: char) -> Result<(&str, &str), ParseError> {
+    match line.split_once(separator) {
+        Some((key, value)) => Ok((key.trim(), value.trim())),
+        None => Err(ParseError::new(line.chars().count() + 1, ParseErrorKind::MissingSeparator(separator))),
+    }
+}
+
+fn parse_numbered<'a, T>(lines: impl Iterator<Item = (usize, &'a str)>,
+                         mut parse: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    lines
+        .map(|(number, line)| parse(line).map_err(|err| err.at_line(number)))
+        .collect()
+}
+
+/// Parses every line, errors carry the line they came from
+pub fn lines<'a, T>(input: &'a str, parse: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    parse_numbered(input.lines().enumerate().map(|(i, line)| (i + 1, line)), parse)
+}
+
+/// A run of non-blank lines
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block<'a> {
+    /// 1-based line number of the first line in the whole input
+    pub first_line: usize,
+    pub lines: Vec<&'a str>
+}
+
+impl<'a> Block<'a> {
+    /// Each line with its line number in the whole input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines.iter().enumerate().map(|(i, line)| (self.first_line + i, *line))
+    }
+
+    pub fn parse_lines<T>(&self, parse: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        parse_numbered(self.numbered_lines(), parse)
+    }
+
+    /// Fails on the first line of the block unless it has exactly `expected` lines
+    pub fn expect_lines(&self, expected: usize) -> Result<(), ParseError> {
+        match self.lines.len() == expected {
+            true => Ok(()),
+            false => Err(ParseError::new(1, ParseErrorKind::Count { what: "lines", expected, found: self.lines.len() })
+                .at_line(self.first_line)),
+        }
+    }
+}
+
+/// The blocks of an input separated by blank lines
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut current: Option<Block> = None;
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+            continue;
+        }
+        current
+            .get_or_insert_with(|| Block { first_line: i + 1, lines: Vec::new() })
+            .lines
+            .push(line);
+    }
+    blocks.extend(current);
+    blocks
+}
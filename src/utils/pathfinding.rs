@@ -0,0 +1,189 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use num::Zero;
+use crate::utils::grid::{Grid, Position};
+
+/// The states from start to goal, both included, and what it cost to walk them
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C
+}
+
+/// Successors for walking a grid orthogonally over cells that are `passable`, one step at a time:
+/// `bfs(start, grid_moves(&grid, |&cell| cell != '#'), |&position| position == end)`
+pub fn grid_moves<'a, T>(grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a) -> impl Fn(&Position) -> Vec<Position> + 'a {
+    move |&position| grid.neighbours_4(position).filter(|&next| passable(&grid[next])).collect()
+}
+
+/// `grid_moves` with a cost of 1 per step, for `dijkstra` and `astar`
+pub fn grid_weighted_moves<'a, T>(grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a) -> impl Fn(&Position) -> Vec<(Position, usize)> + 'a {
+    let moves = grid_moves(grid, passable);
+    move |position| moves(position).into_iter().map(|next| (next, 1)).collect()
+}
+
+/// Walks `parents` back from `goal` to the state without a parent
+fn walk_back<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(parent) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+/// Fewest steps from `start` to a state that satisfies `goal`
+pub fn bfs<S, N, I, G>(start: S, mut successors: N, mut goal: G) -> Option<Path<S, usize>>
+where S: Clone + Eq + Hash, N: FnMut(&S) -> I, I: IntoIterator<Item = S>, G: FnMut(&S) -> bool {
+    let mut parents: HashMap<S, S> = HashMap::new();
+    let mut seen: HashSet<S> = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if goal(&state) {
+            let states = walk_back(&parents, state);
+            return Some(Path { cost: states.len() - 1, states });
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Everything Dijkstra or A* learned about the states it settled
+struct Search<S, C> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    cost: Vec<C>,
+    /// Every predecessor on a cheapest way to the state
+    parents: Vec<Vec<usize>>,
+    /// Number of cheapest ways to the state
+    ways: Vec<u64>,
+    /// Goal states reached at the lowest cost
+    goals: Vec<usize>
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord + Zero> Search<S, C> {
+    /// Settles states by increasing cost plus `heuristic` until no goal can be reached cheaper.
+    /// With `all_goals` it goes on to find every goal at the lowest cost instead of stopping at the first.
+    fn run<N, I, G, H>(start: S, mut successors: N, mut goal: G, mut heuristic: H, all_goals: bool) -> Search<S, C>
+    where N: FnMut(&S) -> I, I: IntoIterator<Item = (S, C)>, G: FnMut(&S) -> bool, H: FnMut(&S) -> C {
+        let mut search = Search {
+            states: vec![start.clone()],
+            ids: HashMap::from([(start.clone(), 0)]),
+            cost: vec![C::zero()],
+            parents: vec![Vec::new()],
+            ways: vec![1],
+            goals: Vec::new()
+        };
+        let mut settled = vec![false];
+        let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), 0)]);
+        let mut best: Option<C> = None;
+
+        while let Some((Reverse(estimate), id)) = queue.pop() {
+            if settled[id] {
+                continue;
+            }
+            if best.is_some_and(|best| estimate > best) {
+                break;
+            }
+            settled[id] = true;
+            let state = search.states[id].clone();
+            if goal(&state) {
+                best = Some(search.cost[id]);
+                search.goals.push(id);
+                if !all_goals {
+                    break;
+                }
+                continue;
+            }
+            for (next, step) in successors(&state) {
+                let cost = search.cost[id] + step;
+                let next_id = match search.ids.get(&next) {
+                    Some(&next_id) => next_id,
+                    None => {
+                        let next_id = search.states.len();
+                        search.ids.insert(next.clone(), next_id);
+                        search.states.push(next.clone());
+                        search.cost.push(cost);
+                        search.parents.push(Vec::new());
+                        search.ways.push(0);
+                        settled.push(false);
+                        next_id
+                    }
+                };
+                if settled[next_id] || cost > search.cost[next_id] {
+                    continue;
+                }
+                if cost < search.cost[next_id] || search.parents[next_id].is_empty() {
+                    search.cost[next_id] = cost;
+                    search.parents[next_id].clear();
+                    search.ways[next_id] = 0;
+                    queue.push((Reverse(cost + heuristic(&next)), next_id));
+                }
+                search.parents[next_id].push(id);
+                search.ways[next_id] += search.ways[id];
+            }
+        }
+        search
+    }
+
+    fn path(&self) -> Option<Path<S, C>> {
+        let &goal = self.goals.first()?;
+        let mut ids = vec![goal];
+        while let Some(&parent) = self.parents[*ids.last().unwrap()].first() {
+            ids.push(parent);
+        }
+        ids.reverse();
+        Some(Path { states: ids.iter().map(|&id| self.states[id].clone()).collect(), cost: self.cost[goal] })
+    }
+}
+
+/// Cheapest path from `start` to a state that satisfies `goal`, costs may not be negative
+pub fn dijkstra<S, C, N, I, G>(start: S, successors: N, goal: G) -> Option<Path<S, C>>
+where S: Clone + Eq + Hash, C: Copy + Ord + Zero, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, C)>, G: FnMut(&S) -> bool {
+    Search::run(start, successors, goal, |_| C::zero(), false).path()
+}
+
+/// Dijkstra guided by `heuristic`, which may never overestimate the cost left to a goal,
+/// and may not drop by more than the cost of a step (like the manhattan distance on a grid)
+pub fn astar<S, C, N, I, G, H>(start: S, successors: N, goal: G, heuristic: H) -> Option<Path<S, C>>
+where S: Clone + Eq + Hash, C: Copy + Ord + Zero, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, C)>,
+      G: FnMut(&S) -> bool, H: FnMut(&S) -> C {
+    Search::run(start, successors, goal, heuristic, false).path()
+}
+
+/// The lowest cost to any goal and how many different paths reach a goal at that cost.
+/// Every step has to cost more than zero, or paths could go around in circles for free.
+pub fn count_shortest_paths<S, C, N, I, G>(start: S, successors: N, goal: G) -> Option<(C, u64)>
+where S: Clone + Eq + Hash, C: Copy + Ord + Zero, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, C)>, G: FnMut(&S) -> bool {
+    let search = Search::run(start, successors, goal, |_| C::zero(), true);
+    let &first = search.goals.first()?;
+    Some((search.cost[first], search.goals.iter().map(|&goal| search.ways[goal]).sum()))
+}
+
+/// The lowest cost to any goal and every state that is on at least one path at that cost
+pub fn nodes_on_shortest_paths<S, C, N, I, G>(start: S, successors: N, goal: G) -> Option<(C, HashSet<S>)>
+where S: Clone + Eq + Hash, C: Copy + Ord + Zero, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, C)>, G: FnMut(&S) -> bool {
+    let search = Search::run(start, successors, goal, |_| C::zero(), true);
+    let &first = search.goals.first()?;
+    let mut on_path = vec![false; search.states.len()];
+    let mut stack = search.goals.clone();
+    while let Some(id) = stack.pop() {
+        if !on_path[id] {
+            on_path[id] = true;
+            stack.extend(&search.parents[id]);
+        }
+    }
+    let states = search.states
+        .iter()
+        .zip(on_path)
+        .filter(|(_, on_path)| *on_path)
+        .map(|(state, _)| state.clone())
+        .collect();
+    Some((search.cost[first], states))
+}
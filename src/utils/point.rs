@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use crate::utils::direction::Direction;
+use crate::utils::grid::Position;
+
+/// A point or offset in the plane. On grids `x` is the column and `y` the row, so `y` grows southward.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point2D<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T> Point2D<T> {
+    pub const fn new(x: T, y: T) -> Point2D<T> {
+        Point2D { x, y }
+    }
+}
+
+fn abs_diff<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    a.max(b) - a.min(b)
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point2D<T> {
+    pub fn manhattan_distance(self, other: Point2D<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Steps needed when diagonal steps are allowed
+    pub fn chebyshev_distance(self, other: Point2D<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Add<Output = T> + From<i8>> Point2D<T> {
+    pub fn step(self, direction: Direction) -> Point2D<T> {
+        self + direction.delta()
+    }
+}
+
+impl Point2D<i64> {
+    pub fn from_position((row, col): Position) -> Point2D<i64> {
+        Point2D::new(col as i64, row as i64)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn add(self, other: Point2D<T>) -> Point2D<T> {
+        Point2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn sub(self, other: Point2D<T>) -> Point2D<T> {
+        Point2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn neg(self) -> Point2D<T> {
+        Point2D::new(-self.x, -self.y)
+    }
+}
+
+/// Scaling, e.g. a velocity times a number of seconds
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn mul(self, factor: T) -> Point2D<T> {
+        Point2D::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2D<T> {
+    fn add_assign(&mut self, other: Point2D<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2D<T> {
+    fn sub_assign(&mut self, other: Point2D<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Display> Display for Point2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::utils::direction::Direction;
+use crate::utils::grid::{Grid, GridError, Position};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WordMatch {
+    pub start: Position,
+    pub direction: Direction
+}
+
+/// Whether `sequence` can be read from `start` in a straight line in `direction`
+pub fn sequence_at<T: PartialEq>(grid: &Grid<T>, start: Position, direction: Direction, sequence: &[T]) -> bool {
+    let mut cells = grid.ray(start, direction).map(|(_, cell)| cell);
+    sequence.iter().all(|expected| cells.next() == Some(expected))
+}
+
+pub fn word_at(grid: &Grid<char>, start: Position, direction: Direction, word: &str) -> bool {
+    let mut cells = grid.ray(start, direction).map(|(_, cell)| *cell);
+    word.chars().all(|expected| cells.next() == Some(expected))
+}
+
+/// Every start position and direction, out of `directions`, from which `sequence` can be read
+pub fn find_sequence<T: PartialEq>(grid: &Grid<T>, sequence: &[T], directions: &[Direction]) -> Vec<WordMatch> {
+    let Some(first) = sequence.first() else {
+        return Vec::new();
+    };
+    grid.iter()
+        .filter(|(_, cell)| *cell == first)
+        .flat_map(|(start, _)| directions.iter().map(move |&direction| WordMatch { start, direction }))
+        .filter(|found| sequence_at(grid, found.start, found.direction, sequence))
+        .collect()
+}
+
+pub fn find_word(grid: &Grid<char>, word: &str, directions: &[Direction]) -> Vec<WordMatch> {
+    find_sequence(grid, &word.chars().collect::<Vec<char>>(), directions)
+}
+
+/// A rectangular pattern, cells that are `None` match anything
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern<T> {
+    cells: Grid<Option<T>>
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PatternMatch {
+    pub top_left: Position,
+    /// Quarter turns clockwise of the pattern that matched
+    pub rotation: usize
+}
+
+impl Pattern<char> {
+    /// Parses a pattern like `"M.S\n.A.\nM.S"` where `wildcard` (here `.`) matches anything
+    pub fn parse(pattern: &str, wildcard: char) -> Result<Pattern<char>, GridError> {
+        let cells = Grid::parse(pattern, |char| match char == wildcard {
+            true => None,
+            false => Some(char),
+        })?;
+        Ok(Pattern { cells })
+    }
+}
+
+impl<T: Clone + PartialEq> Pattern<T> {
+    pub fn new(cells: Grid<Option<T>>) -> Pattern<T> {
+        Pattern { cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn rotate_clockwise(&self) -> Pattern<T> {
+        Pattern { cells: self.cells.rotate_clockwise() }
+    }
+
+    /// The distinct rotations with their number of quarter turns, a symmetric pattern has fewer than four
+    pub fn rotations(&self) -> Vec<(usize, Pattern<T>)> {
+        let mut rotations: Vec<(usize, Pattern<T>)> = Vec::new();
+        let mut rotated = self.clone();
+        for quarter_turns in 0..4 {
+            if !rotations.iter().any(|(_, pattern)| *pattern == rotated) {
+                rotations.push((quarter_turns, rotated.clone()));
+            }
+            rotated = rotated.rotate_clockwise();
+        }
+        rotations
+    }
+
+    pub fn matches_at(&self, grid: &Grid<T>, (top, left): Position) -> bool {
+        self.cells.iter().all(|((row, col), expected)| match expected {
+            Some(expected) => grid.get(top + row, left + col) == Some(expected),
+            None => top + row < grid.height() && left + col < grid.width(),
+        })
+    }
+
+    /// Top left corners of every place the pattern fits
+    pub fn find(&self, grid: &Grid<T>) -> Vec<Position> {
+        let rows = (grid.height() + 1).saturating_sub(self.height());
+        let cols = (grid.width() + 1).saturating_sub(self.width());
+        (0..rows)
+            .flat_map(|top| (0..cols).map(move |left| (top, left)))
+            .filter(|&top_left| self.matches_at(grid, top_left))
+            .collect()
+    }
+
+    /// Matches of any rotation of the pattern
+    pub fn find_rotated(&self, grid: &Grid<T>) -> Vec<PatternMatch> {
+        self.rotations()
+            .into_iter()
+            .flat_map(|(rotation, pattern)| {
+                pattern.find(grid).into_iter().map(move |top_left| PatternMatch { top_left, rotation })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::fs;
+use advent_of_code_2024::answers::{Answers, AnswersError, Hint, Verdict};
+use advent_of_code_2024::solution::Part;
+use common::ScratchDir;
+
+#[test]
+fn unknown_parts_and_known_answers() {
+    let mut answers = Answers::empty(2024);
+    assert_eq!(answers.verify(13, Part::One, "480"), Verdict::Unknown);
+
+    answers.record_answer(13, Part::One, "480").unwrap();
+    assert_eq!(answers.verify(13, Part::One, "480"), Verdict::Pass);
+    assert_eq!(answers.verify(13, Part::One, "481"), Verdict::Fail(String::from("expected 480")));
+    // the other part of the day is still unknown
+    assert_eq!(answers.verify(13, Part::Two, "480"), Verdict::Unknown);
+}
+
+#[test]
+fn a_previous_wrong_guess_fails() {
+    let mut answers = Answers::empty(2024);
+    answers.record_guess(7, Part::Two, "1234", Hint::Wrong);
+    assert_eq!(answers.verify(7, Part::Two, "1234"), Verdict::Fail(String::from("already guessed, wrong")));
+    assert_eq!(answers.verify(7, Part::Two, "1235"), Verdict::Unknown);
+}
+
+#[test]
+fn too_high_and_too_low_guesses_bound_the_answer() {
+    let mut answers = Answers::empty(2024);
+    answers.record_guess(13, Part::One, "500", Hint::High);
+    answers.record_guess(13, Part::One, "600", Hint::High);
+    answers.record_guess(13, Part::One, "100", Hint::Low);
+
+    assert_eq!(answers.verify(13, Part::One, "550"), Verdict::Fail(String::from("too high, must be below 500")));
+    assert_eq!(answers.verify(13, Part::One, "500"), Verdict::Fail(String::from("already guessed, too high")));
+    assert_eq!(answers.verify(13, Part::One, "50"), Verdict::Fail(String::from("too low, must be above 100")));
+    assert_eq!(answers.verify(13, Part::One, "480"), Verdict::Unknown);
+    // answers that are not numbers have no bounds
+    assert_eq!(answers.verify(13, Part::One, "abc"), Verdict::Unknown);
+}
+
+#[test]
+fn refuses_a_conflicting_correct_answer() {
+    let mut answers = Answers::empty(2024);
+    answers.record_answer(13, Part::One, "480").unwrap();
+    answers.record_answer(13, Part::One, "480").unwrap();
+
+    let err = answers.record_answer(13, Part::One, "481").unwrap_err();
+    assert!(matches!(&err, AnswersError::Conflict { day: 13, part: Part::One, recorded } if recorded == "480"), "{}", err);
+    assert_eq!(answers.verify(13, Part::One, "480"), Verdict::Pass);
+}
+
+#[test]
+fn round_trips_through_toml() {
+    let scratch = ScratchDir::new("answers-toml");
+    let dir = scratch.path();
+
+    // a year without a file has no answers yet
+    assert_eq!(Answers::load_from(dir, 2024).unwrap(), Answers::empty(2024));
+
+    let mut answers = Answers::empty(2024);
+    answers.record_answer(13, Part::One, "480").unwrap();
+    answers.record_guess(13, Part::Two, "32297", Hint::High);
+    answers.save_to(dir).unwrap();
+
+    let text = fs::read_to_string(dir.join("2024.toml")).unwrap();
+    assert!(text.contains("[day13.part1]"), "{}", text);
+    assert_eq!(Answers::load_from(dir, 2024).unwrap(), answers);
+
+    fs::write(dir.join("2023.toml"), "day24 = 5").unwrap();
+    assert!(matches!(Answers::load_from(dir, 2023), Err(AnswersError::Parse(..))));
+}
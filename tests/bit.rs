@@ -0,0 +1,54 @@
+use advent_of_code_2024::utils::bit::{flip_bit, get_bit, least_common, majority, minority, most_common, set_bit, BitError, BitVec};
+
+#[test]
+fn integer_bits_count_from_the_least_significant() {
+    assert!(get_bit(0b100u8, 2) && !get_bit(0b100u8, 0));
+    assert_eq!(set_bit(0u64, 63, true), 1 << 63);
+    assert_eq!(set_bit(0b111i32, 1, false), 0b101);
+    assert_eq!(flip_bit(0b101u16, 1), 0b111);
+}
+
+#[test]
+fn binary_strings_and_integers() {
+    let bits = BitVec::from_binary("10110").unwrap();
+    assert_eq!((bits.len(), bits.count_ones(), bits.count_zeros()), (5, 3, 2));
+    assert_eq!(bits.to_int::<u8>(), Some(22));
+    assert_eq!(bits.ones().collect::<Vec<_>>(), vec![0, 2, 3]);
+    assert_eq!(bits.to_string(), "10110");
+    assert_eq!(BitVec::from_int(22u32, 5), bits);
+    assert_eq!(BitVec::from_int(5u8, 12).to_binary(), "000000000101");
+    assert_eq!(BitVec::from_binary("10a"), Err(BitError::InvalidDigit { index: 2, found: 'a' }));
+
+    // values wider than one word, and integers too small to hold them
+    let wide = BitVec::from_int(u128::MAX - 1, 128);
+    assert_eq!(wide.to_int::<u128>(), Some(u128::MAX - 1));
+    assert_eq!(wide.to_int::<u64>(), None);
+    assert_eq!(wide.ones().count(), 127);
+    assert_eq!(BitVec::from_binary("0000000011").unwrap().to_int::<u8>(), Some(3));
+}
+
+#[test]
+fn changing_and_combining_bits() {
+    let mut bits = BitVec::new(70);
+    bits.set(69, true);
+    bits.flip(3);
+    bits.flip(3);
+    bits.flip(0);
+    assert_eq!(bits.ones().collect::<Vec<_>>(), vec![0, 69]);
+
+    // inverting leaves the unused bits of the last word alone
+    let inverted = !bits.clone();
+    assert_eq!(inverted.count_ones(), 68);
+    assert_eq!((bits.clone() | &inverted).count_ones(), 70);
+    assert_eq!((bits.clone() & &inverted).count_ones(), 0);
+    assert_eq!((bits ^ &BitVec::new(70)).count_ones(), 2);
+}
+
+#[test]
+fn column_majorities() {
+    let rows: Vec<BitVec> = ["110", "100", "011", "010"].iter().map(|row| BitVec::from_binary(row).unwrap()).collect();
+    assert_eq!(majority(&rows, true).to_binary(), "110");
+    assert_eq!(majority(&rows, false).to_binary(), "010");
+    assert_eq!(minority(&rows, false).to_binary(), "001");
+    assert!(most_common(&rows[..3], 0, false) && !least_common(&rows[..3], 0, true));
+}
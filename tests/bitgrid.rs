@@ -0,0 +1,51 @@
+use advent_of_code_2024::utils::bitgrid::BitGrid;
+use advent_of_code_2024::utils::direction::Direction;
+use advent_of_code_2024::utils::grid::Grid;
+
+#[test]
+fn works_as_a_visited_set() {
+    let grid = Grid::new(70, 3, '.');
+    let mut visited = BitGrid::for_grid(&grid);
+    assert!(visited.is_empty());
+    assert!(visited.insert((0, 0)));
+    assert!(visited.insert((2, 69)));
+    assert!(!visited.insert((0, 0)));
+    assert!(visited.contains((2, 69)) && !visited.contains((1, 69)));
+    assert_eq!(visited.count(), 2);
+    assert_eq!(visited.positions().collect::<Vec<_>>(), vec![(0, 0), (2, 69)]);
+
+    visited.remove((0, 0));
+    assert_eq!(visited.positions().collect::<Vec<_>>(), vec![(2, 69)]);
+    visited.clear();
+    assert!(visited.is_empty());
+}
+
+#[test]
+fn direction_planes_tell_a_loop_apart_from_a_crossing() {
+    let mut walked = BitGrid::with_directions(4, 4);
+    assert!(walked.insert_directed((1, 1), Direction::North));
+    // crossing the same cell in another direction is new, facing the same way again is not
+    assert!(walked.insert_directed((1, 1), Direction::East));
+    assert!(!walked.insert_directed((1, 1), Direction::North));
+    assert!(walked.contains((1, 1)) && walked.contains_directed((1, 1), Direction::East));
+    assert!(!walked.contains_directed((1, 1), Direction::South));
+    assert_eq!((walked.count(), walked.count_directed()), (1, 2));
+}
+
+#[test]
+fn union_merges_sets() {
+    let mut left = BitGrid::new(8, 8);
+    let mut right = BitGrid::new(8, 8);
+    left.insert((0, 1));
+    right.insert((0, 1));
+    right.insert((7, 7));
+    left.union(&right);
+    assert_eq!(left.count(), 2);
+    assert_eq!(left, right);
+}
+
+#[test]
+#[should_panic(expected = "outside the 8x8 grid")]
+fn positions_outside_the_grid_panic() {
+    BitGrid::new(8, 8).insert((8, 0));
+}
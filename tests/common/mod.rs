@@ -0,0 +1,45 @@
+// shared by the integration tests through `mod common;`, not every test file uses every helper
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Numbers the directories of a test binary, so tests running in parallel never share one
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+/// An empty directory in the system temp dir that is removed again when dropped
+pub struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    /// `name` only makes a leftover directory recognizable, the process id and a counter make it unique
+    pub fn new(name: &str) -> ScratchDir {
+        let number = NEXT.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}-{}", name, std::process::id(), number));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        ScratchDir(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Writes `file`, relative to the directory, creating its parent directories
+    pub fn write(&self, file: &str, contents: impl AsRef<[u8]>) -> PathBuf {
+        let path = self.0.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    pub fn read(&self, file: &str) -> String {
+        fs::read_to_string(self.0.join(file)).unwrap()
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
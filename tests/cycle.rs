@@ -0,0 +1,44 @@
+use advent_of_code_2024::utils::cycle::{brent, fast_forward, find_cycle, floyd, try_find_cycle, Cycle};
+
+/// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+fn rho(state: &u32) -> u32 {
+    match state {
+        6 => 3,
+        _ => state + 1,
+    }
+}
+
+#[test]
+fn all_detectors_agree() {
+    let expected = Cycle { start: 3, length: 4 };
+    assert_eq!(floyd(0, rho), expected);
+    assert_eq!(brent(0, rho), expected);
+    assert_eq!(find_cycle(0, rho), expected);
+    assert_eq!(brent(4, rho), Cycle { start: 0, length: 4 });
+
+    // a pseudo random sequence with a long tail
+    let lcg = |state: &u64| (state * state + 1) % 1009;
+    assert_eq!(floyd(2, lcg), find_cycle(2, lcg));
+    assert_eq!(brent(2, lcg), find_cycle(2, lcg));
+}
+
+#[test]
+fn walks_that_end_have_no_cycle() {
+    let ending = |&state: &u32| (state < 10).then_some(state + 1);
+    assert_eq!(try_find_cycle(0, ending), None);
+    assert_eq!(try_find_cycle(0, |state| Some(rho(state))), Some(Cycle { start: 3, length: 4 }));
+}
+
+#[test]
+fn fast_forward_skips_whole_cycles() {
+    let cycle = Cycle { start: 3, length: 4 };
+    assert_eq!(cycle.reduce(2), 2);
+    assert_eq!(cycle.reduce(7), 3);
+    assert_eq!(cycle.reduce(1_000_000_001), 5);
+
+    for steps in [0, 2, 3, 6, 7, 100] {
+        let stepped = (0..steps).fold(0, |state, _| rho(&state));
+        assert_eq!(fast_forward(0, rho, steps), stepped);
+    }
+    assert_eq!(fast_forward(0, rho, 1_000_000_001), 5);
+}
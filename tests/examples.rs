@@ -0,0 +1,77 @@
+use advent_of_code_2024::runner;
+use advent_of_code_2024::solution::{InputKind, Part};
+use advent_of_code_2024::solutions::REGISTRY;
+use advent_of_code_2024::utils;
+
+fn check_example(year: u16, day: u8, part: Part) {
+    let solution = REGISTRY.get(year, day).unwrap_or_else(|| panic!("no solution registered for {} day {}", year, day));
+    let expected = solution
+        .example_answer(part)
+        .unwrap_or_else(|| panic!("{} day {} declares no example answer for part {}", year, day, part));
+    let input = utils::read_example(year, day, part).unwrap_or_else(|err| panic!("{}", err));
+
+    let result = runner::run_part(solution, &input, InputKind::Example, part).unwrap_or_else(|message| panic!("{}", message));
+    assert_eq!(result.answer, expected, "{} day {} part {} on the example input", year, day, part);
+}
+
+/// One `#[test]` per listed part, in a module per day so `cargo test day13` picks out a day.
+/// The tests below check the list against the registry, so a day or a declared answer cannot be left out.
+macro_rules! example_tests {
+    ($($module:ident: $year:expr, $day:expr => $($part:ident),+;)*) => {
+        $(
+            mod $module {
+                $(
+                    #[test]
+                    fn $part() {
+                        super::check_example($year, $day, example_tests!(@part $part));
+                    }
+                )+
+            }
+        )*
+
+        const TESTED: &[(u16, u8, Part)] = &[$($(($year, $day, example_tests!(@part $part)),)+)*];
+    };
+    (@part part_one) => { advent_of_code_2024::solution::Part::One };
+    (@part part_two) => { advent_of_code_2024::solution::Part::Two };
+}
+
+example_tests! {
+    day01: 2024, 1 => part_one, part_two;
+    day02: 2024, 2 => part_one, part_two;
+    day03: 2024, 3 => part_one, part_two;
+    day04: 2024, 4 => part_one, part_two;
+    day06: 2024, 6 => part_one, part_two;
+    day07: 2024, 7 => part_one, part_two;
+    day09: 2024, 9 => part_one;
+    day11: 2024, 11 => part_one;
+    day13: 2024, 13 => part_one;
+    day14: 2024, 14 => part_one;
+    day17: 2024, 17 => part_one;
+    day22: 2024, 22 => part_one;
+    day23: 2024, 23 => part_one, part_two;
+    day24_2023: 2023, 24 => part_one;
+    day2_2021: 2021, 2 => part_one, part_two;
+    day3_2021: 2021, 3 => part_one, part_two;
+    day4_2021: 2021, 4 => part_one, part_two;
+}
+
+#[test]
+fn every_declared_example_is_tested() {
+    for solution in REGISTRY.all() {
+        for part in [Part::One, Part::Two] {
+            if solution.example_answer(part).is_some() {
+                assert!(TESTED.contains(&(solution.year(), solution.day(), part)),
+                        "{} day {} part {} declares an example answer but has no test", solution.year(), solution.day(), part);
+            }
+        }
+    }
+}
+
+/// A day without example answers would not be tested at all, e.g. right after `new`
+#[test]
+fn every_day_declares_an_example_answer() {
+    for solution in REGISTRY.all() {
+        assert!([Part::One, Part::Two].iter().any(|&part| solution.example_answer(part).is_some()),
+                "{} day {} declares no example answers", solution.year(), solution.day());
+    }
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use advent_of_code_2024::fetch::{is_unlocked, FetchError, FetchOptions, Fetched, Fetcher, UreqClient};
+use common::ScratchDir;
+
+/// Answers every request with the same status and body, and keeps the request heads
+struct StubServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>
+}
+
+impl StubServer {
+    fn start(status: u16, body: &'static str) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut head = String::new();
+                let mut reader = BufReader::new(&stream);
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                recorded.lock().unwrap().push(head);
+                write!(stream, "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            }
+        });
+        StubServer { url, requests }
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn fetcher(server: &StubServer, input_dir: &Path, min_interval: Duration) -> Fetcher<UreqClient> {
+    let options = FetchOptions {
+        base_url: server.url.clone(),
+        input_dir: input_dir.to_path_buf(),
+        user_agent: String::from("fetch-test"),
+        min_interval
+    };
+    Fetcher::new(UreqClient::new(), Some(String::from("secret")), options)
+}
+
+#[test]
+fn downloads_once_and_then_uses_the_cache() {
+    let server = StubServer::start(200, "1 2 3\n");
+    let dir = ScratchDir::new("fetch-cache");
+    let mut fetcher = fetcher(&server, dir.path(), Duration::ZERO);
+
+    let path = dir.path().join("day01.txt");
+    assert_eq!(fetcher.fetch(2024, 1).unwrap(), Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+    assert_eq!(fetcher.fetch(2024, 1).unwrap(), Fetched::Cached(path));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    assert!(requests[0].to_lowercase().contains("cookie: session=secret"));
+    assert!(requests[0].to_lowercase().contains("user-agent: fetch-test"));
+}
+
+#[test]
+fn fills_in_an_empty_placeholder() {
+    let server = StubServer::start(200, "input");
+    let dir = ScratchDir::new("fetch-placeholder");
+    let path = dir.write("2023/day24.txt", "");
+    let mut fetcher = fetcher(&server, dir.path(), Duration::ZERO);
+
+    assert_eq!(fetcher.fetch(2023, 24).unwrap(), Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(path).unwrap(), "input");
+}
+
+#[test]
+fn error_responses_are_not_cached() {
+    let server = StubServer::start(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+    let dir = ScratchDir::new("fetch-error");
+    let mut fetcher = fetcher(&server, dir.path(), Duration::ZERO);
+
+    let err = fetcher.fetch(2024, 2).unwrap_err();
+    assert!(matches!(err, FetchError::Status { status: 400, .. }), "{}", err);
+    assert!(!dir.path().join("day02.txt").exists());
+}
+
+#[test]
+fn waits_between_requests() {
+    let server = StubServer::start(200, "input");
+    let dir = ScratchDir::new("fetch-rate-limit");
+    let mut fetcher = fetcher(&server, dir.path(), Duration::from_millis(300));
+
+    let start = Instant::now();
+    fetcher.fetch(2024, 3).unwrap();
+    fetcher.fetch(2024, 4).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn puzzles_unlock_at_midnight_est() {
+    // 2024-12-01 05:00:00 UTC
+    let unlock = UNIX_EPOCH + Duration::from_secs(1733029200);
+    assert!(!is_unlocked(2024, 1, unlock - Duration::from_secs(1)));
+    assert!(is_unlocked(2024, 1, unlock));
+    assert!(!is_unlocked(2024, 2, unlock));
+    assert!(is_unlocked(2015, 25, SystemTime::now()));
+}
@@ -0,0 +1,53 @@
+use advent_of_code_2024::utils::graph::Graph;
+
+fn names(graph: &Graph, ids: &[usize]) -> Vec<String> {
+    let mut names: Vec<String> = ids.iter().map(|&id| graph.name(id).to_string()).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn interns_names_and_stores_edges_both_ways() {
+    let graph = Graph::undirected_from_edges([("a", "b"), ("b", "c"), ("a", "b")]);
+    assert_eq!((graph.len(), graph.edge_count()), (3, 2));
+    let (a, b, c) = (graph.id("a").unwrap(), graph.id("b").unwrap(), graph.id("c").unwrap());
+    assert!(graph.has_edge(b, a) && graph.has_edge(b, c));
+    assert!(!graph.has_edge(a, c));
+    assert_eq!(graph.id("d"), None);
+
+    let directed = Graph::directed_from_edges([("a", "b")]);
+    assert!(directed.has_edge(0, 1) && !directed.has_edge(1, 0));
+    assert_eq!(directed.edge_count(), 1);
+}
+
+#[test]
+fn searches_and_components() {
+    let graph = Graph::undirected_from_edges([("a", "b"), ("a", "c"), ("b", "d"), ("x", "y")]);
+    let a = graph.id("a").unwrap();
+    assert_eq!(graph.bfs(a).iter().map(|&id| graph.name(id)).collect::<Vec<_>>(), vec!["a", "b", "c", "d"]);
+    assert_eq!(graph.dfs(a).iter().map(|&id| graph.name(id)).collect::<Vec<_>>(), vec!["a", "b", "d", "c"]);
+
+    let components: Vec<Vec<String>> = graph.connected_components().iter().map(|component| names(&graph, component)).collect();
+    assert_eq!(components, vec![vec!["a", "b", "c", "d"], vec!["x", "y"]]);
+
+    // directed edges only connect one way, but still make one component
+    let directed = Graph::directed_from_edges([("a", "b"), ("c", "b")]);
+    assert_eq!(directed.bfs(0).len(), 2);
+    assert_eq!(directed.connected_components().len(), 1);
+}
+
+#[test]
+fn triangles_and_cliques() {
+    // a square with one diagonal, plus a tail
+    let graph = Graph::undirected_from_edges([("a", "b"), ("b", "c"), ("c", "d"), ("d", "a"), ("a", "c"), ("d", "e")]);
+    let triangles: Vec<Vec<String>> = graph.triangles().iter().map(|triangle| names(&graph, triangle)).collect();
+    assert_eq!(triangles, vec![vec!["a", "b", "c"], vec!["a", "c", "d"]]);
+
+    let mut cliques: Vec<Vec<String>> = graph.maximal_cliques().iter().map(|clique| names(&graph, clique)).collect();
+    cliques.sort();
+    assert_eq!(cliques, vec![vec!["a", "b", "c"], vec!["a", "c", "d"], vec!["d", "e"]]);
+
+    let complete = Graph::undirected_from_edges([("a", "b"), ("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "d"), ("d", "d")]);
+    assert_eq!(names(&complete, &complete.maximum_clique()), vec!["a", "b", "c", "d"]);
+    assert_eq!(complete.triangles().len(), 4);
+}
@@ -0,0 +1,54 @@
+use advent_of_code_2024::utils::grid::{Grid, GridError};
+
+const INPUT: &str = "abc\ndef\n";
+
+fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+    cells.collect()
+}
+
+#[test]
+fn parses_row_by_row() {
+    let grid = Grid::parse_chars(INPUT).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 0), Some(&'d'));
+    assert_eq!(grid.get(0, 3), None);
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get_signed(-1, 0), None);
+    assert_eq!(grid[(0, 2)], 'c');
+    assert_eq!(grid.to_string(), INPUT);
+
+    let digits = Grid::parse("12\n34", |char| char.to_digit(10).unwrap()).unwrap();
+    assert_eq!(digits.rows().map(|row| row.iter().sum::<u32>()).collect::<Vec<_>>(), vec![3, 7]);
+}
+
+#[test]
+fn rejects_ragged_and_empty_input() {
+    assert_eq!(Grid::parse_chars("abc\nde\n"), Err(GridError::Ragged { line: 2, expected: 3, found: 2 }));
+    assert_eq!(Grid::parse_chars("\n"), Err(GridError::Empty));
+    assert_eq!(Grid::from_vec(2, 2, vec![1, 2, 3]), Err(GridError::Size { width: 2, height: 2, cells: 3 }));
+}
+
+#[test]
+fn get_mut_writes_in_place() {
+    let mut grid = Grid::new(2, 2, 0);
+    *grid.get_mut(1, 1).unwrap() = 5;
+    grid[(0, 1)] = 3;
+    assert_eq!(grid, Grid::from_vec(2, 2, vec![0, 3, 0, 5]).unwrap());
+    assert_eq!(grid.find(|&cell| cell == 5), Some((1, 1)));
+}
+
+#[test]
+fn iterates_columns_and_diagonals() {
+    let grid = Grid::parse_chars(INPUT).unwrap();
+    assert_eq!(grid.columns().map(collect).collect::<Vec<_>>(), vec!["ad", "be", "cf"]);
+    assert_eq!(grid.diagonals().map(collect).collect::<Vec<_>>(), vec!["d", "ae", "bf", "c"]);
+    assert_eq!(grid.anti_diagonals().map(collect).collect::<Vec<_>>(), vec!["a", "bd", "ce", "f"]);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::parse_chars(INPUT).unwrap();
+    assert_eq!(grid.neighbours_4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours_4((1, 1)).collect::<Vec<_>>(), vec![(0, 1), (1, 2), (1, 0)]);
+    assert_eq!(grid.neighbours_8((1, 1)).collect::<Vec<_>>(), vec![(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]);
+}
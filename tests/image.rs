@@ -0,0 +1,27 @@
+use advent_of_code_2024::utils::grid::Grid;
+use advent_of_code_2024::utils::image::Image;
+
+const WALL: [u8; 3] = [255, 255, 255];
+const FLOOR: [u8; 3] = [0, 0, 0];
+
+#[test]
+fn scales_every_cell_to_a_block() {
+    let grid = Grid::parse_chars("#.\n..\n.#").unwrap();
+    let image = Image::from_grid(&grid, 2, |&cell| if cell == '#' { WALL } else { FLOOR });
+    assert_eq!((image.width(), image.height()), (4, 6));
+    assert_eq!(image.pixel(1, 1), Some(WALL));
+    assert_eq!(image.pixel(2, 1), Some(FLOOR));
+    assert_eq!(image.pixel(3, 5), Some(WALL));
+    assert_eq!(image.pixel(4, 0), None);
+}
+
+#[test]
+fn writes_binary_ppm() {
+    let grid = Grid::parse_chars("#.").unwrap();
+    let image = Image::from_grid(&grid, 1, |&cell| if cell == '#' { WALL } else { FLOOR });
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    let mut expected = b"P6\n2 1\n255\n".to_vec();
+    expected.extend([255, 255, 255, 0, 0, 0]);
+    assert_eq!(ppm, expected);
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use advent_of_code_2024::utils::input::{input_dir_from, input_path_in, read_input_from_path, read_input_in, InputError};
+use common::ScratchDir;
+
+#[test]
+fn prefers_the_nested_layout() {
+    let dir = ScratchDir::new("input-nested");
+    let flat = dir.write("day24_2023.txt", b"flat");
+    assert_eq!(input_path_in(dir.path(), 2023, 24).unwrap(), flat);
+
+    let nested = dir.write("2023/day24.txt", b"nested");
+    assert_eq!(input_path_in(dir.path(), 2023, 24).unwrap(), nested);
+    assert_eq!(read_input_in(dir.path(), 2023, 24).unwrap(), "nested");
+}
+
+#[test]
+fn falls_back_to_the_flat_layout() {
+    let dir = ScratchDir::new("input-flat");
+    let day06 = dir.write("day06.txt", b"2024");
+    let day5 = dir.write("day5_2022.txt", b"2022");
+    assert_eq!(input_path_in(dir.path(), 2024, 6).unwrap(), day06);
+    assert_eq!(input_path_in(dir.path(), 2022, 5).unwrap(), day5);
+    // the flat name of 2024 has no year, the nested one is still found
+    let nested = dir.write("2024/day07.txt", b"2024");
+    assert_eq!(input_path_in(dir.path(), 2024, 7).unwrap(), nested);
+}
+
+#[test]
+fn the_environment_overrides_the_input_dir() {
+    assert_eq!(input_dir_from(None), PathBuf::from("input"));
+    assert_eq!(input_dir_from(Some(OsString::from("/srv/aoc"))), PathBuf::from("/srv/aoc"));
+}
+
+#[test]
+fn reports_every_candidate_of_a_missing_input() {
+    let dir = ScratchDir::new("input-missing");
+    let err = input_path_in(dir.path(), 2023, 24).unwrap_err();
+    let InputError::Missing(candidates) = err else {
+        panic!("expected a missing input, got {}", err);
+    };
+    assert_eq!(candidates, vec![dir.path().join("2023").join("day24.txt"), dir.path().join("day24_2023.txt")]);
+    assert!(matches!(read_input_in(dir.path(), 2023, 24), Err(InputError::Missing(_))));
+    assert!(matches!(read_input_from_path(dir.path().join("day01.txt")), Err(InputError::Missing(_))));
+}
+
+#[test]
+fn refuses_empty_and_invalid_inputs() {
+    let dir = ScratchDir::new("input-invalid");
+    let empty = dir.write("day01.txt", b" \n\n");
+    assert!(matches!(read_input_from_path(&empty), Err(InputError::Empty(path)) if path == empty));
+    assert!(matches!(read_input_in(dir.path(), 2024, 1), Err(InputError::Empty(_))));
+
+    let binary = dir.write("day02.txt", [0xff, 0xfe, b'\n']);
+    assert!(matches!(read_input_from_path(&binary), Err(InputError::NotUtf8(path)) if path == binary));
+}
@@ -0,0 +1,74 @@
+use advent_of_code_2024::utils::linear::{solve, solve_i128, solve_integers, Solutions};
+use num::bigint::BigInt;
+use num::rational::{BigRational, Ratio};
+
+fn rational(numerator: i64, denominator: i64) -> BigRational {
+    BigRational::new(numerator.into(), denominator.into())
+}
+
+#[test]
+fn unique_solutions_are_exact() {
+    // the first claw machine of day 13
+    let solutions = solve_integers(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+    assert_eq!(solutions.integers(), Some(vec![BigInt::from(80), BigInt::from(40)]));
+
+    // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27, with a zero on the first pivot position after swapping
+    let solutions = solve_integers(&[vec![0, 2, 5], vec![1, 1, 1], vec![2, 5, -1]], &[-4, 6, 27]);
+    assert_eq!(solutions, Solutions::Unique(vec![rational(5, 1), rational(3, 1), rational(-2, 1)]));
+
+    // 3x = 1 is fine as a rational, but not whole
+    let solutions = solve_integers(&[vec![3]], &[1]);
+    assert_eq!(solutions, Solutions::Unique(vec![rational(1, 3)]));
+    assert_eq!(solutions.integers(), None);
+}
+
+#[test]
+fn large_coordinates_stay_exact() {
+    // day 24 (2023) scale values, where f64 starts rounding
+    let position = 19_000_000_000_000_017i64;
+    let solutions = solve_integers(&[vec![3, -1], vec![1, 1]], &[position, position + 4]);
+    assert_eq!(solutions, Solutions::Unique(vec![rational(2 * position + 4, 4), rational(2 * position + 12, 4)]));
+
+    let half = |value: i64| rational(value, 2);
+    let solutions = solve(vec![vec![half(1), half(1)], vec![half(1), half(-1)]], vec![half(position), half(1)]);
+    assert_eq!(solutions, Solutions::Unique(vec![rational(position + 1, 2), rational(position - 1, 2)]));
+}
+
+#[test]
+fn contradictions_and_free_unknowns() {
+    // parallel lines never meet
+    assert_eq!(solve_integers(&[vec![1, 2], vec![2, 4]], &[3, 7]), Solutions::None);
+
+    // the same line twice leaves y free
+    let solutions = solve_integers(&[vec![1, 2], vec![2, 4]], &[3, 6]);
+    assert_eq!(solutions, Solutions::Infinite { particular: vec![rational(3, 1), rational(0, 1)], free: vec![1] });
+    assert_eq!(solutions.integers(), None);
+
+    // more equations than unknowns is fine as long as they agree
+    let solutions = solve_integers(&[vec![1], vec![2], vec![-1]], &[2, 4, -2]);
+    assert_eq!(solutions, Solutions::Unique(vec![rational(2, 1)]));
+}
+
+#[test]
+fn i128_solutions_are_reduced_like_ratio_new() {
+    // two hailstones of day 24 (2023), 19, 13 @ -2, 1 and 18, 19 @ -1, -1
+    let solutions = solve_i128(&[vec![-2, 1], vec![1, 1]], &[-1, 6]).unwrap();
+    assert_eq!(solutions, Solutions::Unique(vec![Ratio::new(7, 3), Ratio::new(11, 3)]));
+
+    // the signs end up in the numerators, like with `Ratio::new`
+    let solutions = solve_i128(&[vec![0, 2, 5], vec![1, 1, 1], vec![2, 5, -1]], &[-4, 6, 27]).unwrap();
+    assert_eq!(solutions.integers(), Some(vec![5, 3, -2]));
+    assert_eq!(solve_i128(&[vec![-6]], &[4]).unwrap(), Solutions::Unique(vec![Ratio::new(-2, 3)]));
+
+    assert_eq!(solve_i128(&[vec![1, 2], vec![2, 4]], &[3, 7]).unwrap(), Solutions::None);
+    let solutions = solve_i128(&[vec![1, 2], vec![2, 4]], &[3, 6]).unwrap();
+    assert_eq!(solutions, Solutions::Infinite { particular: vec![Ratio::from_integer(3), Ratio::from_integer(0)], free: vec![1] });
+}
+
+#[test]
+fn i128_overflow_is_none() {
+    let big = i64::MAX;
+    let matrix = [vec![big, 1, 0], vec![1, big, 1], vec![0, 1, big]];
+    assert_eq!(solve_i128(&matrix, &[big, big, big]), None);
+    assert!(matches!(solve_integers(&matrix, &[big, big, big]), Solutions::Unique(_)));
+}
@@ -0,0 +1,43 @@
+use advent_of_code_2024::utils::math::{crt, extended_gcd, gcd, lcm, lcm_all, linear_diophantine, mod_inverse, mod_pow, mod_sub,
+                                       modulo, solve_2x2};
+
+#[test]
+fn gcd_lcm_and_extended_euclid() {
+    assert_eq!((gcd(12, 18), lcm(4, 6), lcm_all([2u64, 3, 4, 5])), (6, 12, 60));
+    for (a, b) in [(240i64, 46), (-12, 18), (7, 0), (0, -5)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn modular_arithmetic() {
+    assert_eq!((modulo(-7, 5), modulo(7, 5), mod_sub(2, 9, 5)), (3, 2, 3));
+    assert_eq!(mod_pow(3i64, 200, 1_000_000_007), 136_318_165);
+    assert_eq!(mod_pow(5, 0, 1), 0);
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(4, 8), None);
+}
+
+#[test]
+fn chinese_remainder_theorem() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // moduli sharing a factor still combine when they agree on it
+    assert_eq!(crt(&[(1i64, 4), (3, 6)]), Some((9, 12)));
+    assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+    assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+}
+
+#[test]
+fn diophantine_equations() {
+    let (x, y) = linear_diophantine(94i64, 22, 8400).unwrap();
+    assert_eq!(94 * x + 22 * y, 8400);
+    assert_eq!(linear_diophantine(4, 6, 7), None);
+
+    // the first claw machine of day 13
+    assert_eq!(solve_2x2([[94i64, 22], [34, 67]], [8400, 5400]), Some((80, 40)));
+    assert_eq!(solve_2x2([[26, 67], [66, 21]], [12748, 12176]), None);
+    assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+}
@@ -0,0 +1,67 @@
+use advent_of_code_2024::utils::memo::{recording, Memo, MemoStats};
+
+#[test]
+fn recursion_goes_through_the_cache() {
+    let mut memo = Memo::new();
+    let fibonacci = |fib: &mut dyn FnMut(u64) -> u64, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) };
+    assert_eq!(memo.recurse(90, &fibonacci), 2_880_067_194_370_816_120);
+    // every n is computed once, and looked up once more by the n + 2 above it, down to 3
+    assert_eq!(memo.stats(), MemoStats { hits: 88, misses: 91, entries: 91 });
+
+    assert_eq!(memo.recurse(10, &fibonacci), 55);
+    assert_eq!(memo.stats().hits, 89);
+}
+
+#[test]
+fn plain_lookups_count_too() {
+    let mut memo: Memo<&str, usize> = Memo::new();
+    assert_eq!(memo.get_or_insert_with("stone", || 5), 5);
+    assert_eq!(memo.get_or_insert_with("stone", || unreachable!()), 5);
+    assert_eq!(memo.get(&"pebble"), None);
+    let stats = memo.stats();
+    assert_eq!((stats.hits, stats.misses, stats.lookups()), (1, 2, 3));
+    assert_eq!(stats.to_string(), "33.3% of 3");
+
+    memo.clear();
+    assert!(memo.is_empty());
+}
+
+#[test]
+fn records_the_memos_used_in_a_run() {
+    let (answer, stats) = recording(|| {
+        let mut memo = Memo::new();
+        memo.get_or_insert_with(1, || 2) + memo.get_or_insert_with(1, || 2)
+    });
+    assert_eq!(answer, 4);
+    assert_eq!(stats, Some(MemoStats { hits: 1, misses: 1, entries: 1 }));
+
+    // memos outside a recording are not counted
+    drop(Memo::<u8, u8>::new());
+    assert_eq!(recording(|| 0).1, None);
+}
+
+#[test]
+fn recordings_on_other_threads_are_separate() {
+    let (_, stats) = recording(|| {
+        let mut memo = Memo::new();
+        memo.get_or_insert_with(1, || 2);
+        std::thread::spawn(|| {
+            let (_, stats) = recording(|| Memo::<u8, u8>::new().get_or_insert_with(3, || 4));
+            assert_eq!(stats, Some(MemoStats { hits: 0, misses: 1, entries: 1 }));
+            // a memo of another thread outside its own recording is not counted either
+            Memo::<u8, u8>::new().get_or_insert_with(5, || 6);
+        }).join().unwrap();
+        memo.get_or_insert_with(1, || 2)
+    });
+    assert_eq!(stats, Some(MemoStats { hits: 1, misses: 1, entries: 1 }));
+}
+
+#[test]
+fn nested_recordings_count_towards_the_outer_one() {
+    let ((_, inner), outer) = recording(|| {
+        Memo::<u8, u8>::new().get_or_insert_with(1, || 2);
+        recording(|| Memo::<u8, u8>::new().get_or_insert_with(3, || 4))
+    });
+    assert_eq!(inner, Some(MemoStats { hits: 0, misses: 1, entries: 1 }));
+    assert_eq!(outer, Some(MemoStats { hits: 0, misses: 2, entries: 2 }));
+}
@@ -0,0 +1,51 @@
+use advent_of_code_2024::utils::parse;
+use advent_of_code_2024::utils::parse::{ParseError, ParseErrorKind};
+
+#[test]
+fn signed_ints_reads_numbers_from_free_text() {
+    assert_eq!(parse::signed_ints::<i32>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+    assert_eq!(parse::signed_ints::<i64>("Button A: X+94, Y+34").unwrap(), vec![94, 34]);
+    assert_eq!(parse::signed_ints::<i32>("1-2 -x --5").unwrap(), vec![1, 2, -5]);
+    assert_eq!(parse::signed_ints::<i32>("no numbers").unwrap(), Vec::<i32>::new());
+    assert_eq!(parse::signed_ints_exact::<i32, 2>("a=1 b=2").unwrap(), [1, 2]);
+}
+
+#[test]
+fn errors_point_at_the_bad_token() {
+    let err = parse::signed_ints::<u8>("x=12 y=300").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 8));
+    assert!(matches!(err.kind, ParseErrorKind::InvalidNumber { ref token, .. } if token == "300"));
+
+    let err = parse::separated::<i32>("1, 2,x3", ',').unwrap_err();
+    assert_eq!((err.line, err.column), (1, 6));
+
+    let err = parse::lines("1 2\n3 4 5", parse::signed_ints_exact::<i32, 2>).unwrap_err();
+    assert_eq!(err, ParseError { line: 2, column: 1, kind: ParseErrorKind::Count { what: "numbers", expected: 2, found: 3 } });
+    assert_eq!(err.to_string(), "line 2, column 1: expected 2 numbers, found 3");
+}
+
+#[test]
+fn separated_skips_repeated_separators() {
+    assert_eq!(parse::separated::<i32>(" 22 13  17 ", ' ').unwrap(), vec![22, 13, 17]);
+    assert_eq!(parse::separated::<f64>("19, 13, 30", ',').unwrap(), vec![19.0, 13.0, 30.0]);
+}
+
+#[test]
+fn key_value_splits_at_the_first_separator() {
+    assert_eq!(parse::key_value("Register A: 729", ':').unwrap(), ("Register A", "729"));
+    assert_eq!(parse::key_value("190: 10: 19", ':').unwrap(), ("190", "10: 19"));
+    let err = parse::key_value("abc", ':').unwrap_err();
+    assert_eq!((err.column, err.kind), (4, ParseErrorKind::MissingSeparator(':')));
+}
+
+#[test]
+fn blocks_keep_their_line_numbers() {
+    let blocks = parse::blocks("a\nb\n\n\nc\r\n\nd 1\nd 2 3\n");
+    assert_eq!(blocks.iter().map(|block| block.first_line).collect::<Vec<_>>(), vec![1, 5, 7]);
+    assert_eq!(blocks[0].lines, vec!["a", "b"]);
+    assert_eq!(blocks[1].lines, vec!["c"]);
+
+    let err = blocks[2].parse_lines(parse::signed_ints_exact::<i32, 1>).unwrap_err();
+    assert_eq!(err.line, 8);
+    assert_eq!(blocks[2].expect_lines(3).unwrap_err().line, 7);
+}
@@ -0,0 +1,69 @@
+use advent_of_code_2024::utils::grid::{Grid, Position};
+use advent_of_code_2024::utils::pathfinding::{astar, bfs, count_shortest_paths, dijkstra, grid_moves, grid_weighted_moves,
+                                                nodes_on_shortest_paths};
+
+const MAZE: &str = "\
+S..#
+.#..
+...E";
+
+fn find(grid: &Grid<char>, mark: char) -> Position {
+    grid.find(|&cell| cell == mark).unwrap()
+}
+
+#[test]
+fn bfs_finds_the_fewest_steps_on_a_grid() {
+    let grid = Grid::parse_chars(MAZE).unwrap();
+    let (start, end) = (find(&grid, 'S'), find(&grid, 'E'));
+    let path = bfs(start, grid_moves(&grid, |&cell| cell != '#'), |&position| position == end).unwrap();
+    assert_eq!(path.cost, 5);
+    assert_eq!(path.states.len(), 6);
+    assert_eq!((path.states[0], path.states[5]), (start, end));
+
+    let walled = Grid::parse_chars("S#E").unwrap();
+    assert_eq!(bfs((0, 0), grid_moves(&walled, |&cell| cell != '#'), |&position| position == (0, 2)), None);
+}
+
+#[test]
+fn dijkstra_and_astar_agree_on_the_cost() {
+    let grid = Grid::parse_chars(MAZE).unwrap();
+    let (start, end) = (find(&grid, 'S'), find(&grid, 'E'));
+    let cheapest = dijkstra(start, grid_weighted_moves(&grid, |&cell| cell != '#'), |&position| position == end).unwrap();
+    let manhattan = |&(row, col): &Position| row.abs_diff(end.0) + col.abs_diff(end.1);
+    let guided = astar(start, grid_weighted_moves(&grid, |&cell| cell != '#'), |&position| position == end, manhattan).unwrap();
+    assert_eq!((cheapest.cost, guided.cost), (5, 5));
+    assert_eq!(guided.states.first(), Some(&start));
+    assert_eq!(guided.states.last(), Some(&end));
+}
+
+#[test]
+fn dijkstra_prefers_cheap_detours() {
+    // a -> c directly costs 10, through b only 3
+    let successors = |&node: &char| match node {
+        'a' => vec![('b', 1), ('c', 10)],
+        'b' => vec![('c', 2)],
+        _ => vec![],
+    };
+    let path = dijkstra('a', successors, |&node| node == 'c').unwrap();
+    assert_eq!(path.states, vec!['a', 'b', 'c']);
+    assert_eq!(path.cost, 3u32);
+}
+
+#[test]
+fn counts_and_collects_all_shortest_paths() {
+    // on an open 3x3 grid there are 6 shortest paths between opposite corners, using every cell
+    let grid = Grid::new(3, 3, '.');
+    let moves = || grid_weighted_moves(&grid, |_| true);
+    assert_eq!(count_shortest_paths((0, 0), moves(), |&position| position == (2, 2)), Some((4, 6)));
+    let (cost, nodes) = nodes_on_shortest_paths((0, 0), moves(), |&position| position == (2, 2)).unwrap();
+    assert_eq!((cost, nodes.len()), (4, 9));
+
+    // two goals at the same distance both count, the wall only leaves the left column and bottom row
+    let grid = Grid::parse_chars("S..\n.#.\n...").unwrap();
+    let moves = || grid_weighted_moves(&grid, |&cell| cell != '#');
+    let goals = |&position: &Position| position == (2, 1) || position == (1, 2);
+    assert_eq!(count_shortest_paths((0, 0), moves(), goals), Some((3, 2)));
+    let (_, nodes) = nodes_on_shortest_paths((0, 0), moves(), goals).unwrap();
+    assert_eq!(nodes.len(), 7);
+    assert!(!nodes.contains(&(2, 2)) && !nodes.contains(&(1, 1)));
+}
@@ -0,0 +1,37 @@
+use advent_of_code_2024::utils::direction::Direction;
+use advent_of_code_2024::utils::point::Point2D;
+
+#[test]
+fn arithmetic_and_distances() {
+    let a = Point2D::new(1, -2);
+    let b = Point2D::new(-3, 4);
+    assert_eq!(a + b, Point2D::new(-2, 2));
+    assert_eq!(a - b, Point2D::new(4, -6));
+    assert_eq!(-a, Point2D::new(-1, 2));
+    assert_eq!(a * 3, Point2D::new(3, -6));
+    assert_eq!(a.manhattan_distance(b), 10);
+    assert_eq!(a.chebyshev_distance(b), 6);
+    assert_eq!(Point2D::new(2u32, 7).manhattan_distance(Point2D::new(5, 1)), 9);
+}
+
+#[test]
+fn rotations_go_all_the_way_around() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.rotate_clockwise().rotate_counter_clockwise(), direction);
+        assert_eq!(direction.rotate_clockwise().rotate_clockwise(), direction.opposite());
+        assert_eq!(direction.rotate_clockwise_45().rotate_clockwise_45(), direction.rotate_clockwise());
+        assert_eq!(direction.rotate_clockwise().is_diagonal(), direction.is_diagonal());
+        assert_eq!(direction.delta::<i32>() + direction.opposite().delta(), Point2D::new(0, 0));
+    }
+    assert_eq!(Direction::North.rotate_clockwise(), Direction::East);
+    assert_eq!(Direction::NorthEast.rotate_clockwise(), Direction::SouthEast);
+    assert_eq!(Direction::SouthWest.rotate_counter_clockwise(), Direction::SouthEast);
+}
+
+#[test]
+fn steps_follow_screen_coordinates() {
+    let start = Point2D::new(5i64, 5);
+    assert_eq!(start.step(Direction::North), Point2D::new(5, 4));
+    assert_eq!(start.step(Direction::SouthWest), Point2D::new(4, 6));
+    assert_eq!(Point2D::from_position((2, 7)), Point2D::new(7, 2));
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+use advent_of_code_2024::bench;
+use advent_of_code_2024::bench::BenchOptions;
+use advent_of_code_2024::runner;
+use advent_of_code_2024::runner::{DaySelection, InputSource, RunError};
+use advent_of_code_2024::solution::{InputKind, Part, Registry, Solution};
+
+/// Answers with the length of its input, examples are cut in half. Nothing of 1999 has an input file.
+struct Length<const DAY: u8>;
+
+impl<const DAY: u8> Solution for Length<DAY> {
+    const YEAR: u16 = 1999;
+    const DAY: u8 = DAY;
+
+    type Input<'a> = &'a str;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn parse_example(input: &str) -> &str {
+        &input[..input.len() / 2]
+    }
+
+    fn part_one(input: &&str) -> usize {
+        input.len()
+    }
+
+    fn part_two(input: &&str) -> usize {
+        input.len()
+    }
+}
+
+static REGISTRY: Registry = Registry::new(&[&Length::<1>, &Length::<2>]);
+
+#[test]
+fn a_missing_input_only_fails_its_own_rows() {
+    let reports = runner::run(&REGISTRY, 1999, DaySelection::All, &[Part::One, Part::Two], &InputSource::Puzzle).unwrap();
+    assert_eq!(reports.iter().map(|report| report.day).collect::<Vec<u8>>(), vec![1, 2]);
+    for report in &reports {
+        assert_eq!(report.parts.len(), 2);
+        let message = report.parts[0].outcome.as_ref().unwrap_err();
+        assert!(message.contains("not found"), "{}", message);
+    }
+}
+
+#[test]
+fn text_input_is_used_for_a_single_day() {
+    let source = InputSource::Text(String::from("abcd"));
+    let reports = runner::run(&REGISTRY, 1999, DaySelection::Day(2), &[Part::One], &source).unwrap();
+    assert_eq!(reports[0].parts[0].outcome.as_ref().unwrap().answer, "4");
+}
+
+#[test]
+fn examples_are_parsed_with_their_own_settings() {
+    let solution = REGISTRY.get(1999, 1).unwrap();
+    assert_eq!(runner::run_part(solution, "abcd", InputKind::Puzzle, Part::One).unwrap().answer, "4");
+    assert_eq!(runner::run_part(solution, "abcd", InputKind::Example, Part::One).unwrap().answer, "2");
+    assert_eq!(InputSource::Example.kind(), InputKind::Example);
+    assert_eq!(InputSource::Text(String::from("abcd")).kind(), InputKind::Puzzle);
+}
+
+#[test]
+fn an_explicit_input_file_that_cannot_be_read_fails_the_run() {
+    let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+    let result = runner::run(&REGISTRY, 1999, DaySelection::Day(1), &[Part::One], &source);
+    assert!(matches!(result, Err(RunError::Input(_))));
+    assert!(matches!(runner::run(&REGISTRY, 1999, DaySelection::All, &[Part::One], &source), Err(RunError::AmbiguousInput)));
+}
+
+#[test]
+fn bench_reports_a_missing_input_in_its_row() {
+    let options = BenchOptions { warmup: 1, runs: 1 };
+    let benches = bench::bench(&REGISTRY, 1999, DaySelection::All, &[Part::One], &InputSource::Puzzle, options).unwrap();
+    assert_eq!(benches.len(), 2);
+    assert!(benches.iter().all(|part_bench| part_bench.outcome.is_err()));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use advent_of_code_2024::scaffold::{new_day, ScaffoldError};
+use advent_of_code_2024::solution::Registry;
+use advent_of_code_2024::solutions::REGISTRY;
+use common::ScratchDir;
+
+const LIB: &str = "\
+pub mod solutions {
+    use crate::solution::Registry;
+
+    pub mod day01;
+
+    pub static REGISTRY: Registry = Registry::new(&[
+        &day01::Day01,
+    ]);
+}
+";
+
+static EMPTY: Registry = Registry::new(&[]);
+
+/// A repository root with only `src/lib.rs`
+fn scratch_root(name: &str, lib: &str) -> ScratchDir {
+    let root = ScratchDir::new(name);
+    root.write("src/lib.rs", lib);
+    root
+}
+
+#[test]
+fn generates_and_registers_a_day() {
+    let root = scratch_root("scaffold-new", LIB);
+    let scaffolded = new_day(root.path(), &EMPTY, 2023, 8).unwrap();
+    assert_eq!((scaffolded.module.as_str(), scaffolded.struct_name.as_str()), ("day8_2023", "Day08"));
+
+    let solution = root.read("src/solutions/day8_2023.rs");
+    assert!(solution.contains("pub struct Day08;"));
+    assert!(solution.contains("impl Solution for Day08 {"));
+    assert!(solution.contains("const YEAR: u16 = 2023;"));
+    assert!(solution.contains("const DAY: u8 = 8;"));
+    assert!(!solution.contains("Template") && !solution.contains("scaffold.rs"));
+
+    let lib = root.read("src/lib.rs");
+    assert!(lib.contains("    pub mod day01;\n    pub mod day8_2023;\n\n    pub static REGISTRY"), "{}", lib);
+    assert!(lib.contains("        &day01::Day01,\n        &day8_2023::Day08,\n    ]);"), "{}", lib);
+
+    // empty placeholders, fetch fills in the input
+    assert_eq!(root.read("input/day8_2023.txt"), "");
+    assert_eq!(root.read("example_input/day8_2023.txt"), "");
+    assert_eq!(scaffolded.files.len(), 4);
+}
+
+#[test]
+fn keeps_an_existing_input_and_example() {
+    let root = scratch_root("scaffold-example", LIB);
+    let input = root.write("input/day09.txt", "4 5 6");
+    let example = root.write("example_input/day09.txt", "1 2 3");
+
+    let scaffolded = new_day(root.path(), &EMPTY, 2024, 9).unwrap();
+    assert_eq!(root.read("input/day09.txt"), "4 5 6");
+    assert_eq!(root.read("example_input/day09.txt"), "1 2 3");
+    assert!(!scaffolded.files.contains(&input) && !scaffolded.files.contains(&example));
+}
+
+#[test]
+fn refuses_a_day_that_exists() {
+    let root = scratch_root("scaffold-exists", LIB);
+    new_day(root.path(), &EMPTY, 2024, 10).unwrap();
+    root.write("input/day10.txt", "fetched");
+    let lib = root.read("src/lib.rs");
+    assert!(matches!(new_day(root.path(), &EMPTY, 2024, 10), Err(ScaffoldError::AlreadyExists(2024, 10))));
+    assert_eq!(root.read("src/lib.rs"), lib);
+    assert_eq!(root.read("input/day10.txt"), "fetched");
+
+    // registered days count even without their file in the scratch root
+    assert!(matches!(new_day(root.path(), &REGISTRY, 2024, 6), Err(ScaffoldError::AlreadyExists(2024, 6))));
+    assert!(!root.path().join("src/solutions/day06.rs").exists());
+}
+
+#[test]
+fn refuses_invalid_days_and_unknown_layouts() {
+    let root = scratch_root("scaffold-layout", "pub mod solutions {}\n");
+    assert!(matches!(new_day(root.path(), &EMPTY, 2024, 0), Err(ScaffoldError::InvalidDay(0))));
+    assert!(matches!(new_day(root.path(), &EMPTY, 2024, 26), Err(ScaffoldError::InvalidDay(26))));
+    assert!(matches!(new_day(root.path(), &EMPTY, 2024, 12), Err(ScaffoldError::UnexpectedLayout(_))));
+    // nothing is written when lib.rs cannot be updated
+    assert!(!root.path().join("src/solutions").exists());
+    assert!(!root.path().join("input").exists());
+}
@@ -0,0 +1,53 @@
+use advent_of_code_2024::utils::direction::Direction;
+use advent_of_code_2024::utils::grid::Grid;
+use advent_of_code_2024::utils::search::{find_word, Pattern, PatternMatch, WordMatch};
+
+#[test]
+fn finds_words_in_the_requested_directions() {
+    let grid = Grid::parse_chars("XMAS\nMM..\nA.A.\nS..S").unwrap();
+    let mut found = find_word(&grid, "XMAS", &Direction::ALL);
+    found.sort_by_key(|found| found.direction);
+    assert_eq!(found, vec![
+        WordMatch { start: (0, 0), direction: Direction::East },
+        WordMatch { start: (0, 0), direction: Direction::SouthEast },
+        WordMatch { start: (0, 0), direction: Direction::South },
+    ]);
+    assert_eq!(find_word(&grid, "XMAS", &[Direction::North, Direction::West]), vec![]);
+    assert_eq!(find_word(&grid, "SAMX", &Direction::ORTHOGONAL).len(), 2);
+    assert_eq!(find_word(&grid, "SAMX", &Direction::DIAGONAL).len(), 1);
+}
+
+#[test]
+fn searches_south_west_on_narrow_grids() {
+    // taller than wide, the south-west check used to compare the row against the width
+    let grid = Grid::parse_chars("...X\n..M.\n.A..\nS...\n....\n....").unwrap();
+    assert_eq!(find_word(&grid, "XMAS", &[Direction::SouthWest]),
+               vec![WordMatch { start: (0, 3), direction: Direction::SouthWest }]);
+    let grid = Grid::parse_chars("..X\n.M.\nA..\n...\n.X.\nM..").unwrap();
+    assert_eq!(find_word(&grid, "XM", &[Direction::SouthWest]).len(), 2);
+    assert_eq!(find_word(&grid, "XMA", &[Direction::SouthWest]).len(), 1);
+}
+
+#[test]
+fn matches_patterns_with_wildcards_and_rotations() {
+    let grid = Grid::parse_chars("M.S.\n.A..\nM.S.\n....").unwrap();
+    let x_mas = Pattern::parse("M.S\n.A.\nM.S", '.').unwrap();
+    assert_eq!(x_mas.find(&grid), vec![(0, 0)]);
+
+    let rotated = grid.rotate_clockwise();
+    assert_eq!(rotated.to_string(), ".M.M\n..A.\n.S.S\n....\n");
+    assert_eq!(x_mas.find(&rotated), vec![]);
+    assert_eq!(x_mas.find_rotated(&rotated), vec![PatternMatch { top_left: (0, 1), rotation: 1 }]);
+}
+
+#[test]
+fn symmetric_patterns_match_once() {
+    let grid = Grid::parse_chars("#.#\n.#.\n#.#").unwrap();
+    let cross = Pattern::parse("#?#\n?#?\n#?#", '?').unwrap();
+    assert_eq!(cross.rotations().len(), 1);
+    assert_eq!(cross.find_rotated(&grid).len(), 1);
+
+    let wide = Pattern::parse("ab", '.').unwrap();
+    assert_eq!(wide.rotations().iter().map(|(turns, _)| *turns).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!((wide.rotate_clockwise().width(), wide.rotate_clockwise().height()), (1, 2));
+}